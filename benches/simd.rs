@@ -1,7 +1,7 @@
 use std::hint::black_box;
 
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
-use quicksim::prelude::ArrayF32SimdExt;
+use quicksim::prelude::{ArrayF32SimdExt, SliceSimdExt};
 use quicksim::traits::array_u32::ArrayU32SimdExt;
 use rand::rngs::StdRng;
 use rand::seq::IndexedRandom;
@@ -125,6 +125,28 @@ fn min_max_u32(c: &mut Criterion) {
             });
         });
     }
+
+    g.finish();
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let mut g = c.benchmark_group("min_max_u32");
+
+    for size in U32_ARRAY_LEN_TO_CHECK {
+        let data: Vec<_> = (0..size).map(|_| rng.next_u32()).collect();
+
+        g.bench_with_input(BenchmarkId::new("simd", size), &size, |i, _| {
+            i.iter(|| {
+                let _ = black_box(data.min_max_simd());
+            });
+        });
+
+        g.bench_with_input(BenchmarkId::new("two_passes", size), &size, |i, _| {
+            i.iter(|| {
+                let _ = black_box((data.min_simd(), data.max_simd()));
+            });
+        });
+    }
 }
 
 fn min_max_f32(c: &mut Criterion) {
@@ -229,7 +251,7 @@ fn find_iter(array: &[u32], needle: u32) -> Option<usize> {
 
 #[inline]
 fn contains_iter(array: &[u32], needle: u32) -> bool {
-    array.iter().any(|i| *i == needle)
+    array.contains(&needle)
 }
 
 #[inline]
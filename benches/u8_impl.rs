@@ -108,6 +108,29 @@ fn find_u8(c: &mut Criterion) {
     }
 }
 
+fn count_u8(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let mut g = c.benchmark_group("count_u8");
+
+    for size in U32_ARRAY_LEN_TO_CHECK {
+        let data: Vec<u8> = (0..size).map(|_| rng.next_u32() as u8).collect();
+        let needle = data[size / 2];
+
+        g.bench_with_input(BenchmarkId::new("simd", size), &size, |i, _| {
+            i.iter(|| {
+                let _ = black_box(black_box(&data).count_simd(needle));
+            });
+        });
+
+        g.bench_with_input(BenchmarkId::new("iter", size), &size, |i, _| {
+            i.iter(|| {
+                let _ = black_box(count_iter_u8(black_box(&data), needle));
+            });
+        });
+    }
+}
+
 fn min_max_u8(c: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(42);
 
@@ -158,7 +181,7 @@ fn min_max_u8(c: &mut Criterion) {
 
 #[inline]
 pub(crate) fn contains_iter_u8(array: &[u8], needle: u8) -> bool {
-    array.iter().any(|i| *i == needle)
+    array.contains(&needle)
 }
 
 #[inline]
@@ -166,6 +189,11 @@ pub(crate) fn find_iter_u8(array: &[u8], needle: u8) -> Option<usize> {
     array.iter().position(|i| *i == needle)
 }
 
+#[inline]
+fn count_iter_u8(array: &[u8], needle: u8) -> usize {
+    array.iter().filter(|i| **i == needle).count()
+}
+
 #[inline]
 fn min_iter_u8(array: &[u8]) -> Option<u8> {
     array.iter().min().copied()
@@ -176,5 +204,5 @@ fn max_iter_u8(array: &[u8]) -> Option<u8> {
     array.iter().max().copied()
 }
 
-criterion_group!(benches, contains_u8, find_u8, min_max_u8);
+criterion_group!(benches, contains_u8, find_u8, count_u8, min_max_u8);
 criterion_main!(benches);
@@ -0,0 +1,105 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The instruction set an implementation is built upon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// The original, non vectorized implementations.
+    Scalar,
+    /// 128 bit vectors, requiring SSE4.1.
+    Sse,
+    /// 256 bit vectors, requiring AVX2.
+    Avx2,
+    /// 512 bit vectors, requiring AVX-512 F and BW, as well as AVX2.
+    Avx512,
+}
+
+impl Backend {
+    /// All backends, ordered from the slowest to the fastest.
+    pub const ALL: [Backend; 4] = [
+        Backend::Scalar,
+        Backend::Sse,
+        Backend::Avx2,
+        Backend::Avx512,
+    ];
+
+    /// Returns `true` if the running CPU supports the backend.
+    pub fn is_supported(self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            #[cfg(target_arch = "x86_64")]
+            Backend::Sse => is_x86_feature_detected!("sse4.1"),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => is_x86_feature_detected!("avx2"),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx512 => {
+                // The AVX-512 table falls back to AVX2 kernels for some operations.
+                is_x86_feature_detected!("avx2")
+                    && is_x86_feature_detected!("avx512f")
+                    && is_x86_feature_detected!("avx512bw")
+            }
+            #[cfg(not(target_arch = "x86_64"))]
+            _ => false,
+        }
+    }
+
+    /// Returns the fastest backend supported by the running CPU.
+    pub fn detect() -> Backend {
+        Backend::ALL
+            .into_iter()
+            .rev()
+            .find(|backend| backend.is_supported())
+            .unwrap_or(Backend::Scalar)
+    }
+
+    /// Returns the name of the backend, as accepted by `QUICKSIM_BACKEND` and [`Backend::from_str`].
+    pub fn name(self) -> &'static str {
+        match self {
+            Backend::Scalar => "scalar",
+            Backend::Sse => "sse",
+            Backend::Avx2 => "avx2",
+            Backend::Avx512 => "avx512",
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Backend {
+    type Err = BackendError;
+
+    /// Parses a backend from its [name](Backend::name), ignoring the case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Backend::ALL
+            .into_iter()
+            .find(|backend| backend.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| BackendError::UnknownName(s.to_string()))
+    }
+}
+
+/// Error returned when a backend can't be selected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BackendError {
+    /// The running CPU doesn't support the requested backend.
+    Unsupported(Backend),
+    /// The given name doesn't belong to any backend.
+    UnknownName(String),
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::Unsupported(backend) => {
+                write!(f, "backend `{backend}` is not supported by this CPU")
+            }
+            BackendError::UnknownName(name) => write!(f, "unknown backend `{name}`"),
+        }
+    }
+}
+
+impl Error for BackendError {}
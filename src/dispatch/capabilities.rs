@@ -0,0 +1,364 @@
+use std::fmt;
+
+use crate::dispatch::{
+    BACKEND_ENV, Backend, BackendError, COMPILED_BACKEND, kernel_backend, kernels, rejected_env,
+    threshold,
+};
+
+/// The element types operations are provided for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Element {
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+impl Element {
+    /// All element types.
+    pub const ALL: [Element; 10] = [
+        Element::U8,
+        Element::U16,
+        Element::U32,
+        Element::U64,
+        Element::I8,
+        Element::I16,
+        Element::I32,
+        Element::I64,
+        Element::F32,
+        Element::F64,
+    ];
+
+    /// Returns the operations that are provided for the element type.
+    pub fn operations(self) -> &'static [Operation] {
+        use Operation::*;
+
+        match self {
+            Element::U8 => &[
+                Min,
+                Max,
+                Find,
+                Contains,
+                Count,
+                Sum,
+                ArgMin,
+                ArgMax,
+                MinMax,
+                RFind,
+                FindAll,
+                FindAny2,
+                FindAny3,
+                FindAnyOf,
+                FindSubslice,
+                Mismatch,
+                CountInRange,
+                FindInRange,
+                AllEq,
+            ],
+            Element::U32 => &[
+                Min,
+                Max,
+                Find,
+                Contains,
+                Count,
+                Sum,
+                ArgMin,
+                ArgMax,
+                MinMax,
+                RFind,
+                FindAll,
+                Mismatch,
+                CountInRange,
+                FindInRange,
+                AllEq,
+            ],
+            Element::U16 | Element::U64 => &[Min, Max, Find, Contains, Count, MinMax],
+            Element::I8 | Element::I16 | Element::I32 | Element::I64 => {
+                &[Min, Max, Find, Contains, Count, MinMax]
+            }
+            Element::F32 => &[
+                Min,
+                Max,
+                Sum,
+                ArgMin,
+                ArgMax,
+                MinMax,
+                Mismatch,
+                CountInRange,
+                FindInRange,
+            ],
+            Element::F64 => &[Min, Max, Find, Contains, Count, Sum, MinMax],
+        }
+    }
+}
+
+/// The operations, named after the trait methods that perform them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operation {
+    /// `min_simd`
+    Min,
+    /// `max_simd`
+    Max,
+    /// `find_simd`
+    Find,
+    /// `contains_simd`
+    Contains,
+    /// `count_simd`
+    Count,
+    /// `sum_simd`
+    Sum,
+    /// `argmin_simd`
+    ArgMin,
+    /// `argmax_simd`
+    ArgMax,
+    /// `min_max_simd`
+    MinMax,
+    /// `rfind_simd`
+    RFind,
+    /// `find_all_simd`
+    FindAll,
+    /// `find_any2_simd`
+    FindAny2,
+    /// `find_any3_simd`
+    FindAny3,
+    /// `find_any_of_simd`
+    FindAnyOf,
+    /// `find_subslice_simd`
+    FindSubslice,
+    /// `mismatch_simd`, `eq_simd` and `cmp_simd`
+    Mismatch,
+    /// `count_in_range_simd`
+    CountInRange,
+    /// `find_first_in_range_simd`, `any_in_range_simd` and the threshold searches like `find_gt_simd`
+    FindInRange,
+    /// `all_eq_simd` and `is_zero_simd`
+    AllEq,
+}
+
+impl Operation {
+    /// All operations.
+    pub const ALL: [Operation; 19] = [
+        Operation::Min,
+        Operation::Max,
+        Operation::Find,
+        Operation::Contains,
+        Operation::Count,
+        Operation::Sum,
+        Operation::ArgMin,
+        Operation::ArgMax,
+        Operation::MinMax,
+        Operation::RFind,
+        Operation::FindAll,
+        Operation::FindAny2,
+        Operation::FindAny3,
+        Operation::FindAnyOf,
+        Operation::FindSubslice,
+        Operation::Mismatch,
+        Operation::CountInRange,
+        Operation::FindInRange,
+        Operation::AllEq,
+    ];
+}
+
+/// Instruction sets supported by the running CPU and the implementations that are used because of them.
+///
+/// Implements [`Display`](fmt::Display) to print a short summary, meant to be logged at startup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// SSE4.1 has been detected.
+    pub sse41: bool,
+    /// AVX2 has been detected.
+    pub avx2: bool,
+    /// AVX-512 F has been detected.
+    pub avx512f: bool,
+    /// AVX-512 BW has been detected.
+    pub avx512bw: bool,
+    /// The backend fixed by enabling its target features at compile time, if any.
+    pub compiled_backend: Option<Backend>,
+    /// The backend used for all operations.
+    pub active_backend: Backend,
+    /// The value of `QUICKSIM_BACKEND` that has been ignored, because it isn't the name of a backend supported by the
+    /// running CPU, and the reason why.
+    pub rejected_backend_env: Option<(String, BackendError)>,
+}
+
+impl Capabilities {
+    /// Returns all backends supported by the running CPU, ordered from the slowest to the fastest.
+    pub fn supported_backends(&self) -> Vec<Backend> {
+        Backend::ALL
+            .into_iter()
+            .filter(|backend| backend.is_supported())
+            .collect()
+    }
+
+    /// Returns the implementation the active backend uses for `operation` on an array of `element`s with `len` items.
+    ///
+    /// Arrays shorter than the [threshold](crate::threshold) of the operation, as well as operations the backend
+    /// doesn't provide a vectorized kernel for, are handled by [`Backend::Scalar`]. Backends might also use the kernels
+    /// of a smaller instruction set for some element types.
+    pub fn implementation(&self, element: Element, operation: Operation, len: usize) -> Backend {
+        if !element.operations().contains(&operation) || len < threshold(element, operation) {
+            return Backend::Scalar;
+        }
+
+        kernel_backend(self.active_backend, element, operation)
+    }
+}
+
+impl fmt::Display for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let features = [
+            ("sse4.1", self.sse41),
+            ("avx2", self.avx2),
+            ("avx512f", self.avx512f),
+            ("avx512bw", self.avx512bw),
+        ];
+
+        write!(f, "backend: {}", self.active_backend)?;
+
+        if self.compiled_backend == Some(self.active_backend) {
+            f.write_str(" (compile time)")?;
+        }
+
+        f.write_str(", detected:")?;
+
+        let mut any = false;
+        for (name, _) in features.iter().filter(|(_, detected)| *detected) {
+            write!(f, " {name}")?;
+            any = true;
+        }
+
+        if !any {
+            f.write_str(" none")?;
+        }
+
+        if let Some((value, error)) = &self.rejected_backend_env {
+            write!(f, ", ignored {BACKEND_ENV}={value:?}: {error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the instruction sets of the running CPU and the backend used for all operations.
+pub fn capabilities() -> Capabilities {
+    #[cfg(target_arch = "x86_64")]
+    let (sse41, avx2, avx512f, avx512bw) = (
+        is_x86_feature_detected!("sse4.1"),
+        is_x86_feature_detected!("avx2"),
+        is_x86_feature_detected!("avx512f"),
+        is_x86_feature_detected!("avx512bw"),
+    );
+
+    #[cfg(not(target_arch = "x86_64"))]
+    let (sse41, avx2, avx512f, avx512bw) = (false, false, false, false);
+
+    // Resolves the backend first, so a rejected `QUICKSIM_BACKEND` has been recorded.
+    let active_backend = kernels().backend;
+
+    Capabilities {
+        sse41,
+        avx2,
+        avx512f,
+        avx512bw,
+        compiled_backend: COMPILED_BACKEND,
+        active_backend,
+        rejected_backend_env: rejected_env(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dispatch::parse_supported;
+    use crate::dispatch::test::LOCK;
+
+    #[test]
+    fn test_capabilities() {
+        let capabilities = capabilities();
+
+        assert!(capabilities.supported_backends().contains(&Backend::Scalar));
+        assert!(
+            capabilities
+                .supported_backends()
+                .contains(&capabilities.active_backend)
+        );
+        assert_eq!(capabilities.avx2, Backend::Avx2.is_supported());
+        assert_eq!(
+            capabilities.avx512f && capabilities.avx512bw,
+            Backend::Avx512.is_supported()
+        );
+
+        let summary = capabilities.to_string();
+        assert!(summary.starts_with("backend: "));
+        assert!(summary.contains(capabilities.active_backend.name()));
+    }
+
+    #[test]
+    fn test_rejected_backend_env() {
+        let _lock = LOCK.lock().unwrap();
+
+        let capabilities = capabilities();
+        let expected = std::env::var(BACKEND_ENV)
+            .ok()
+            .and_then(|name| Some((name.clone(), parse_supported(&name).err()?)));
+        assert_eq!(capabilities.rejected_backend_env, expected);
+
+        let rejected = Capabilities {
+            rejected_backend_env: Some((
+                "avx3".to_string(),
+                BackendError::UnknownName("avx3".to_string()),
+            )),
+            ..capabilities
+        };
+        assert!(
+            rejected
+                .to_string()
+                .ends_with(", ignored QUICKSIM_BACKEND=\"avx3\": unknown backend `avx3`")
+        );
+    }
+
+    #[test]
+    fn test_implementation() {
+        let _lock = LOCK.lock().unwrap();
+
+        let capabilities = capabilities();
+
+        for element in Element::ALL {
+            for &operation in element.operations() {
+                assert_eq!(
+                    capabilities.implementation(element, operation, 0),
+                    Backend::Scalar
+                );
+                assert_eq!(
+                    capabilities.implementation(element, operation, 1 << 20),
+                    kernel_backend(capabilities.active_backend, element, operation)
+                );
+            }
+        }
+
+        // Not provided for floats.
+        assert_eq!(
+            capabilities.implementation(Element::F32, Operation::Count, 1 << 20),
+            Backend::Scalar
+        );
+
+        // The original implementations don't fall back to anything else.
+        for element in Element::ALL {
+            for &operation in element.operations() {
+                assert_eq!(
+                    kernel_backend(Backend::Scalar, element, operation),
+                    Backend::Scalar
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,440 @@
+use std::ptr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+use crate::float::{NanMode, SumMode};
+
+mod backend;
+mod capabilities;
+pub(crate) mod scalar;
+mod thresholds;
+
+pub use backend::{Backend, BackendError};
+pub use capabilities::{Capabilities, Element, Operation, capabilities};
+pub use thresholds::{DEFAULT_THRESHOLD, calibrate, reset_thresholds, set_threshold, threshold};
+
+/// Name of the environment variable that overrides the automatically detected backend.
+pub const BACKEND_ENV: &str = "QUICKSIM_BACKEND";
+
+/// The start of a block of 64 items and the mask of the occurrences of a needle in it, as found by `find_all`.
+pub(crate) type Block = (usize, u64);
+
+/// Implementations of all `u8` operations.
+pub(crate) struct U8Kernels {
+    pub min: fn(&[u8]) -> Option<u8>,
+    pub max: fn(&[u8]) -> Option<u8>,
+    pub min_max: fn(&[u8]) -> Option<(u8, u8)>,
+    pub find: fn(&[u8], u8) -> Option<usize>,
+    pub rfind: fn(&[u8], u8) -> Option<usize>,
+    pub mismatch: fn(&[u8], &[u8]) -> Option<usize>,
+    pub find_all: fn(&[u8], u8) -> Option<Block>,
+    pub find_any2: fn(&[u8], u8, u8) -> Option<usize>,
+    pub find_any3: fn(&[u8], u8, u8, u8) -> Option<usize>,
+    pub find_any_of: fn(&[u8], &[u8]) -> Option<usize>,
+    pub find_subslice: fn(&[u8], &[u8], usize) -> Option<usize>,
+    pub contains: fn(&[u8], u8) -> bool,
+    pub all_eq: fn(&[u8], u8) -> bool,
+    pub count: fn(&[u8], u8) -> usize,
+    pub count_in_range: fn(&[u8], u8, u8) -> usize,
+    pub find_in_range: fn(&[u8], u8, u8) -> Option<usize>,
+    pub argmin: fn(&[u8]) -> Option<usize>,
+    pub argmax: fn(&[u8]) -> Option<usize>,
+    pub sum: fn(&[u8]) -> u64,
+}
+
+/// Implementations of all `u16` operations.
+pub(crate) struct U16Kernels {
+    pub min: fn(&[u16]) -> Option<u16>,
+    pub max: fn(&[u16]) -> Option<u16>,
+    pub min_max: fn(&[u16]) -> Option<(u16, u16)>,
+    pub find: fn(&[u16], u16) -> Option<usize>,
+    pub contains: fn(&[u16], u16) -> bool,
+    pub count: fn(&[u16], u16) -> usize,
+}
+
+/// Implementations of all `u32` operations.
+pub(crate) struct U32Kernels {
+    pub min: fn(&[u32]) -> Option<u32>,
+    pub max: fn(&[u32]) -> Option<u32>,
+    pub min_max: fn(&[u32]) -> Option<(u32, u32)>,
+    pub find: fn(&[u32], u32) -> Option<usize>,
+    pub rfind: fn(&[u32], u32) -> Option<usize>,
+    pub mismatch: fn(&[u32], &[u32]) -> Option<usize>,
+    pub find_all: fn(&[u32], u32) -> Option<Block>,
+    pub contains: fn(&[u32], u32) -> bool,
+    pub all_eq: fn(&[u32], u32) -> bool,
+    pub count: fn(&[u32], u32) -> usize,
+    pub count_in_range: fn(&[u32], u32, u32) -> usize,
+    pub find_in_range: fn(&[u32], u32, u32) -> Option<usize>,
+    pub argmin: fn(&[u32]) -> Option<usize>,
+    pub argmax: fn(&[u32]) -> Option<usize>,
+    pub sum: fn(&[u32]) -> u64,
+}
+
+/// Implementations of all `u64` operations.
+pub(crate) struct U64Kernels {
+    pub min: fn(&[u64]) -> Option<u64>,
+    pub max: fn(&[u64]) -> Option<u64>,
+    pub min_max: fn(&[u64]) -> Option<(u64, u64)>,
+    pub find: fn(&[u64], u64) -> Option<usize>,
+    pub contains: fn(&[u64], u64) -> bool,
+    pub count: fn(&[u64], u64) -> usize,
+}
+
+/// Implementations of all `i8` operations.
+pub(crate) struct I8Kernels {
+    pub min: fn(&[i8]) -> Option<i8>,
+    pub max: fn(&[i8]) -> Option<i8>,
+    pub min_max: fn(&[i8]) -> Option<(i8, i8)>,
+    pub find: fn(&[i8], i8) -> Option<usize>,
+    pub contains: fn(&[i8], i8) -> bool,
+    pub count: fn(&[i8], i8) -> usize,
+}
+
+/// Implementations of all `i16` operations.
+pub(crate) struct I16Kernels {
+    pub min: fn(&[i16]) -> Option<i16>,
+    pub max: fn(&[i16]) -> Option<i16>,
+    pub min_max: fn(&[i16]) -> Option<(i16, i16)>,
+    pub find: fn(&[i16], i16) -> Option<usize>,
+    pub contains: fn(&[i16], i16) -> bool,
+    pub count: fn(&[i16], i16) -> usize,
+}
+
+/// Implementations of all `i32` operations.
+pub(crate) struct I32Kernels {
+    pub min: fn(&[i32]) -> Option<i32>,
+    pub max: fn(&[i32]) -> Option<i32>,
+    pub min_max: fn(&[i32]) -> Option<(i32, i32)>,
+    pub find: fn(&[i32], i32) -> Option<usize>,
+    pub contains: fn(&[i32], i32) -> bool,
+    pub count: fn(&[i32], i32) -> usize,
+}
+
+/// Implementations of all `i64` operations.
+pub(crate) struct I64Kernels {
+    pub min: fn(&[i64]) -> Option<i64>,
+    pub max: fn(&[i64]) -> Option<i64>,
+    pub min_max: fn(&[i64]) -> Option<(i64, i64)>,
+    pub find: fn(&[i64], i64) -> Option<usize>,
+    pub contains: fn(&[i64], i64) -> bool,
+    pub count: fn(&[i64], i64) -> usize,
+}
+
+/// Implementations of all `f32` operations.
+pub(crate) struct F32Kernels {
+    pub min: fn(&[f32], NanMode) -> Option<f32>,
+    pub max: fn(&[f32], NanMode) -> Option<f32>,
+    pub min_max: fn(&[f32]) -> Option<(f32, f32)>,
+    pub argmin: fn(&[f32]) -> Option<usize>,
+    pub argmax: fn(&[f32]) -> Option<usize>,
+    pub sum: fn(&[f32], SumMode) -> f32,
+    pub mismatch: fn(&[f32], &[f32]) -> Option<usize>,
+    pub count_in_range: fn(&[f32], f32, f32) -> usize,
+    pub find_in_range: fn(&[f32], f32, f32) -> Option<usize>,
+}
+
+/// Implementations of all `f64` operations.
+pub(crate) struct F64Kernels {
+    pub min: fn(&[f64]) -> Option<f64>,
+    pub max: fn(&[f64]) -> Option<f64>,
+    pub min_max: fn(&[f64]) -> Option<(f64, f64)>,
+    pub find: fn(&[f64], f64) -> Option<usize>,
+    pub contains: fn(&[f64], f64) -> bool,
+    pub count: fn(&[f64], f64) -> usize,
+    pub sum: fn(&[f64]) -> f64,
+}
+
+/// A table containing one implementation for every operation of every element type.
+///
+/// Each backend provides a single static instance of this table. The best table for the running CPU is
+/// resolved once and cached, so calling an operation only costs a load and an indirect call.
+pub(crate) struct Kernels {
+    pub backend: Backend,
+    pub u8: U8Kernels,
+    pub u16: U16Kernels,
+    pub u32: U32Kernels,
+    pub u64: U64Kernels,
+    pub i8: I8Kernels,
+    pub i16: I16Kernels,
+    pub i32: I32Kernels,
+    pub i64: I64Kernels,
+    pub f32: F32Kernels,
+    pub f64: F64Kernels,
+}
+
+/// The backend that has been selected at compile time by enabling its target features, if any.
+///
+/// If set, no runtime detection is needed. Returning the table directly allows the compiler to resolve the function
+/// pointers and inline the kernels into the caller.
+const COMPILED_BACKEND: Option<Backend> = if cfg!(not(target_arch = "x86_64")) {
+    // There are no vectorized implementations for other architectures.
+    Some(Backend::Scalar)
+} else if cfg!(all(target_feature = "avx512f", target_feature = "avx512bw")) {
+    Some(Backend::Avx512)
+} else if cfg!(target_feature = "avx2") {
+    Some(Backend::Avx2)
+} else {
+    None
+};
+
+/// The table that got selected at runtime, or null if it has not been resolved yet or the backend fixed at compile
+/// time is used.
+static ACTIVE: AtomicPtr<Kernels> = AtomicPtr::new(ptr::null_mut());
+
+/// Whether `QUICKSIM_BACKEND` has been checked without overriding the backend fixed at compile time.
+static COMPILED_RESOLVED: AtomicBool = AtomicBool::new(false);
+
+/// The value of `QUICKSIM_BACKEND` that got ignored the last time the backend was resolved, and why.
+static REJECTED_ENV: Mutex<Option<(String, BackendError)>> = Mutex::new(None);
+
+/// Returns the kernels that should be used on the running CPU.
+#[inline]
+pub(crate) fn kernels() -> &'static Kernels {
+    let active = ACTIVE.load(Ordering::Relaxed);
+
+    if !active.is_null() {
+        // Safety: `ACTIVE` only ever points to one of the static kernel tables.
+        return unsafe { &*active };
+    }
+
+    if let Some(backend) = COMPILED_BACKEND
+        && COMPILED_RESOLVED.load(Ordering::Relaxed)
+    {
+        return table(backend);
+    }
+
+    resolve()
+}
+
+/// Forces all operations to use `backend`, instead of the automatically detected one.
+///
+/// The backend can also be selected by setting the `QUICKSIM_BACKEND` environment variable to its
+/// [name](Backend::name) before the first operation runs. This is useful to run the same test suite against every
+/// implementation. A backend fixed at compile time by enabling the target features of AVX2 or AVX-512 can be
+/// overridden as well, e.g. to test the scalar implementations in a build with `-C target-cpu=native`.
+///
+/// Returns an error if the running CPU doesn't support the backend.
+pub fn set_backend(backend: Backend) -> Result<(), BackendError> {
+    if !backend.is_supported() {
+        return Err(BackendError::Unsupported(backend));
+    }
+
+    ACTIVE.store(ptr::from_ref(table(backend)).cast_mut(), Ordering::Relaxed);
+    Ok(())
+}
+
+/// Returns the backend used for all operations.
+///
+/// Operations on arrays shorter than the minimum size of a kernel always use the scalar implementation, see
+/// [`Capabilities::implementation`].
+pub fn active_backend() -> Backend {
+    kernels().backend
+}
+
+/// Reverts [`set_backend`]. The backend gets selected again on the next operation, honoring `QUICKSIM_BACKEND`.
+pub fn reset_backend() {
+    ACTIVE.store(ptr::null_mut(), Ordering::Relaxed);
+    COMPILED_RESOLVED.store(false, Ordering::Relaxed);
+}
+
+/// Returns the table of `backend`.
+///
+/// The caller must ensure that the running CPU supports the backend.
+#[inline]
+fn table(backend: Backend) -> &'static Kernels {
+    match backend {
+        Backend::Scalar => &scalar::KERNELS,
+        #[cfg(target_arch = "x86_64")]
+        Backend::Sse => &crate::x86_64::dispatch::SSE,
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 => &crate::x86_64::dispatch::AVX2,
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 => &crate::x86_64::dispatch::AVX512,
+        #[cfg(not(target_arch = "x86_64"))]
+        _ => unreachable!("backend `{backend}` is not supported on this architecture"),
+    }
+}
+
+/// Returns the backend whose vectorized kernel the table of `backend` uses for `operation` on `element`.
+fn kernel_backend(backend: Backend, element: Element, operation: Operation) -> Backend {
+    #[cfg(target_arch = "x86_64")]
+    return crate::x86_64::dispatch::kernel_backend(backend, element, operation);
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        // Only the scalar backend exists on other architectures.
+        let _ = (element, operation);
+        backend
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn resolve() -> &'static Kernels {
+    let name = std::env::var_os(BACKEND_ENV).map(|name| name.to_string_lossy().into_owned());
+    let parsed = name.as_deref().map(parse_supported);
+
+    *REJECTED_ENV
+        .lock()
+        .unwrap_or_else(|error| error.into_inner()) = match (name, &parsed) {
+        (Some(name), Some(Err(error))) => Some((name, error.clone())),
+        _ => None,
+    };
+
+    let overridden = parsed.and_then(Result::ok);
+
+    if let Some(backend) = COMPILED_BACKEND
+        && overridden.is_none_or(|overridden| overridden == backend)
+    {
+        // Keep using the static table, so the kernels can still be inlined.
+        COMPILED_RESOLVED.store(true, Ordering::Relaxed);
+        return table(backend);
+    }
+
+    let kernels = table(overridden.unwrap_or_else(Backend::detect));
+
+    // Don't replace a backend selected by a concurrent `set_backend`.
+    match ACTIVE.compare_exchange(
+        ptr::null_mut(),
+        ptr::from_ref(kernels).cast_mut(),
+        Ordering::Relaxed,
+        Ordering::Relaxed,
+    ) {
+        Ok(_) => kernels,
+        // Safety: `ACTIVE` only ever points to one of the static kernel tables.
+        Err(active) => unsafe { &*active },
+    }
+}
+
+/// Returns the value of `QUICKSIM_BACKEND` that got ignored when the backend was resolved, and why.
+fn rejected_env() -> Option<(String, BackendError)> {
+    REJECTED_ENV
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .clone()
+}
+
+/// Parses the name of a backend the running CPU supports, as given by `QUICKSIM_BACKEND`.
+fn parse_supported(name: &str) -> Result<Backend, BackendError> {
+    let backend = name.parse::<Backend>()?;
+
+    if !backend.is_supported() {
+        return Err(BackendError::Unsupported(backend));
+    }
+
+    Ok(backend)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::original::array::*;
+
+    /// Serializes the tests that change or inspect the global backend and thresholds.
+    pub(crate) static LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_kernels_cached() {
+        let _lock = LOCK.lock().unwrap();
+
+        let first = kernels();
+        let second = kernels();
+        assert!(ptr::eq(first, second));
+
+        match COMPILED_BACKEND {
+            Some(backend) if std::env::var_os(BACKEND_ENV).is_none() => {
+                assert!(ptr::eq(first, table(backend)))
+            }
+            _ => assert!(!ACTIVE.load(Ordering::Relaxed).is_null()),
+        }
+    }
+
+    #[test]
+    fn test_set_backend() {
+        let _lock = LOCK.lock().unwrap();
+
+        let vec: Vec<u32> = (0..1000).map(|i| (i * 7919) % 1013).collect();
+
+        for backend in Backend::ALL {
+            let result = set_backend(backend);
+
+            if !backend.is_supported() {
+                assert_eq!(result, Err(BackendError::Unsupported(backend)));
+                continue;
+            }
+
+            // Backends other than the one fixed at compile time can be selected as well.
+            assert_eq!(result, Ok(()));
+
+            assert!(ptr::eq(kernels(), table(backend)));
+            assert_eq!(active_backend(), backend);
+
+            let kernels = kernels();
+            assert_eq!((kernels.u32.min)(&vec), min_iter_u32(&vec));
+            assert_eq!((kernels.u32.max)(&vec), max_iter_u32(&vec));
+            assert_eq!((kernels.u32.find)(&vec, 1012), find_iter_u32(&vec, 1012));
+            assert_eq!((kernels.u32.count)(&vec, 3), count_iter_u32(&vec, 3));
+        }
+
+        reset_backend();
+
+        if std::env::var(BACKEND_ENV).is_err() {
+            let expected = COMPILED_BACKEND.unwrap_or_else(Backend::detect);
+            assert!(ptr::eq(kernels(), table(expected)));
+        }
+    }
+
+    #[test]
+    fn test_resolve_keeps_selected_backend() {
+        let _lock = LOCK.lock().unwrap();
+
+        // Simulates a `set_backend` that happens while another thread resolves the backend.
+        set_backend(Backend::Scalar).unwrap();
+        let resolved = resolve();
+
+        assert!(ptr::eq(kernels(), &scalar::KERNELS));
+        if COMPILED_BACKEND.is_none() {
+            assert!(ptr::eq(resolved, &scalar::KERNELS));
+        }
+
+        reset_backend();
+    }
+
+    #[test]
+    fn test_parse_backend() {
+        for backend in Backend::ALL {
+            assert_eq!(backend.name().parse::<Backend>(), Ok(backend));
+            assert_eq!(
+                backend.to_string().to_uppercase().parse::<Backend>(),
+                Ok(backend)
+            );
+        }
+
+        assert_eq!(
+            "avx3".parse::<Backend>(),
+            Err(BackendError::UnknownName("avx3".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_supported() {
+        assert_eq!(parse_supported("Scalar"), Ok(Backend::Scalar));
+        assert_eq!(
+            parse_supported("avx3"),
+            Err(BackendError::UnknownName("avx3".to_string()))
+        );
+
+        for backend in Backend::ALL {
+            match backend.is_supported() {
+                true => assert_eq!(parse_supported(backend.name()), Ok(backend)),
+                false => assert_eq!(
+                    parse_supported(backend.name()),
+                    Err(BackendError::Unsupported(backend))
+                ),
+            }
+        }
+    }
+}
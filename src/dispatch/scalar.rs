@@ -0,0 +1,116 @@
+use crate::dispatch::{
+    Backend, F32Kernels, F64Kernels, I8Kernels, I16Kernels, I32Kernels, I64Kernels, Kernels,
+    U8Kernels, U16Kernels, U32Kernels, U64Kernels,
+};
+use crate::original::array::*;
+
+/// Kernels falling back to the original, non vectorized implementations.
+pub(crate) static KERNELS: Kernels = Kernels {
+    backend: Backend::Scalar,
+    u8: U8Kernels {
+        min: min_iter_u8,
+        max: max_iter_u8,
+        min_max: min_max_iter_u8,
+        find: find_iter_u8,
+        rfind: rfind_iter_u8,
+        mismatch: mismatch_iter_u8,
+        all_eq: all_eq_iter_u8,
+        count_in_range: count_in_range_iter_u8,
+        find_in_range: find_in_range_iter_u8,
+        find_all: find_all_iter_u8,
+        find_any2: find_any2_iter_u8,
+        find_any3: find_any3_iter_u8,
+        find_any_of: find_any_of_iter_u8,
+        find_subslice: find_subslice_iter_u8,
+        contains: contains_iter_u8,
+        count: count_iter_u8,
+        argmin: argmin_iter_u8,
+        argmax: argmax_iter_u8,
+        sum: sum_iter_u8,
+    },
+    u16: U16Kernels {
+        min: min_iter_u16,
+        max: max_iter_u16,
+        min_max: min_max_iter_u16,
+        find: find_iter_u16,
+        contains: contains_iter_u16,
+        count: count_iter_u16,
+    },
+    u32: U32Kernels {
+        min: min_iter_u32,
+        max: max_iter_u32,
+        min_max: min_max_iter_u32,
+        find: find_iter_u32,
+        rfind: rfind_iter_u32,
+        mismatch: mismatch_iter_u32,
+        all_eq: all_eq_iter_u32,
+        count_in_range: count_in_range_iter_u32,
+        find_in_range: find_in_range_iter_u32,
+        find_all: find_all_iter_u32,
+        contains: contains_iter_u32,
+        count: count_iter_u32,
+        argmin: argmin_iter_u32,
+        argmax: argmax_iter_u32,
+        sum: sum_iter_u32,
+    },
+    u64: U64Kernels {
+        min: min_iter_u64,
+        max: max_iter_u64,
+        min_max: min_max_iter_u64,
+        find: find_iter_u64,
+        contains: contains_iter_u64,
+        count: count_iter_u64,
+    },
+    i8: I8Kernels {
+        min: min_iter_i8,
+        max: max_iter_i8,
+        min_max: min_max_iter_i8,
+        find: find_iter_i8,
+        contains: contains_iter_i8,
+        count: count_iter_i8,
+    },
+    i16: I16Kernels {
+        min: min_iter_i16,
+        max: max_iter_i16,
+        min_max: min_max_iter_i16,
+        find: find_iter_i16,
+        contains: contains_iter_i16,
+        count: count_iter_i16,
+    },
+    i32: I32Kernels {
+        min: min_iter_i32,
+        max: max_iter_i32,
+        min_max: min_max_iter_i32,
+        find: find_iter_i32,
+        contains: contains_iter_i32,
+        count: count_iter_i32,
+    },
+    i64: I64Kernels {
+        min: min_iter_i64,
+        max: max_iter_i64,
+        min_max: min_max_iter_i64,
+        find: find_iter_i64,
+        contains: contains_iter_i64,
+        count: count_iter_i64,
+    },
+    f32: F32Kernels {
+        min: min_iter_f32,
+        max: max_iter_f32,
+        min_max: min_max_iter_f32,
+        argmin: argmin_iter_f32,
+        argmax: argmax_iter_f32,
+        sum: sum_iter_f32,
+        mismatch: mismatch_iter_f32,
+        count_in_range: count_in_range_iter_f32,
+        find_in_range: find_in_range_iter_f32,
+    },
+    f64: F64Kernels {
+        min: min_iter_f64,
+        max: max_iter_f64,
+        min_max: min_max_iter_f64,
+        find: find_iter_f64,
+        contains: contains_iter_f64,
+        count: count_iter_f64,
+        sum: sum_iter_f64,
+    },
+};
@@ -0,0 +1,532 @@
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::dispatch::{Backend, Element, Kernels, Operation, kernel_backend, kernels, scalar};
+use crate::float::{NanMode, SumMode};
+
+/// The minimum array length from which the vectorized implementations are used, unless changed with
+/// [`set_threshold`] or [`calibrate`].
+pub const DEFAULT_THRESHOLD: usize = 32;
+
+/// Array lengths the implementations get compared at by [`calibrate`].
+const CALIBRATION_LENGTHS: [usize; 15] =
+    [1, 2, 4, 8, 12, 16, 24, 32, 48, 64, 96, 128, 192, 256, 512];
+
+/// Amount of elements processed per timed run, to get measurable durations for small lengths.
+const CALIBRATION_ELEMENTS: usize = 16 * 1024;
+
+/// Amount of timed runs per length and implementation. The fastest one is taken to filter out noise.
+const CALIBRATION_ROUNDS: usize = 5;
+
+/// Amount of consecutive lengths the vectorized implementation has to be slower at to stop lowering the threshold, so
+/// a single noisy measurement doesn't decide.
+const CALIBRATION_LOSSES: usize = 2;
+
+/// One threshold for each operation of each element type.
+static THRESHOLDS: [[AtomicUsize; Operation::ALL.len()]; Element::ALL.len()] =
+    [const { [const { AtomicUsize::new(DEFAULT_THRESHOLD) }; Operation::ALL.len()] };
+        Element::ALL.len()];
+
+/// Returns the minimum array length from which `operation` on `element` uses the vectorized implementation of the
+/// active backend.
+#[inline]
+pub fn threshold(element: Element, operation: Operation) -> usize {
+    THRESHOLDS[element as usize][operation as usize].load(Ordering::Relaxed)
+}
+
+/// Sets the minimum array length from which `operation` on `element` uses the vectorized implementation of the
+/// active backend, e.g. to apply the results of a previous [`calibrate`] run loaded from a config.
+///
+/// Empty arrays are always handled by the original implementation, so thresholds below 1 are raised to 1.
+pub fn set_threshold(element: Element, operation: Operation, threshold: usize) {
+    THRESHOLDS[element as usize][operation as usize].store(threshold.max(1), Ordering::Relaxed);
+}
+
+/// Resets all thresholds to [`DEFAULT_THRESHOLD`].
+pub fn reset_thresholds() {
+    for element in Element::ALL {
+        for operation in Operation::ALL {
+            set_threshold(element, operation, DEFAULT_THRESHOLD);
+        }
+    }
+}
+
+/// Measures the original and the vectorized implementations of every operation on the running CPU and sets each
+/// threshold to the length from which the vectorized implementation is faster. Arrays longer than the longest measured
+/// length of 512 items always use the vectorized implementation.
+///
+/// This is never done automatically, as it takes some milliseconds. The thresholds only apply to the backend that was
+/// active during calibration, so call this after [`set_backend`](crate::set_backend). Operations running concurrently
+/// produce correct results, but may be slower while the calibration is in progress.
+pub fn calibrate() {
+    let active = kernels();
+
+    if active.backend == Backend::Scalar {
+        return;
+    }
+
+    let max_len = CALIBRATION_LENGTHS[CALIBRATION_LENGTHS.len() - 1];
+    let data = CalibrationData::new(max_len);
+
+    for element in Element::ALL {
+        for &operation in element.operations() {
+            if kernel_backend(active.backend, element, operation) == Backend::Scalar {
+                continue;
+            }
+
+            // Run the vectorized implementation for all lengths while measuring.
+            set_threshold(element, operation, 1);
+
+            let mut threshold = max_len;
+            let mut losses = 0;
+
+            for len in CALIBRATION_LENGTHS.into_iter().rev() {
+                let vectorized = measure(|| data.run(active, element, operation, len), len);
+                let original = measure(|| data.run(&scalar::KERNELS, element, operation, len), len);
+
+                if vectorized < original {
+                    threshold = len;
+                    losses = 0;
+                    continue;
+                }
+
+                losses += 1;
+
+                if losses == CALIBRATION_LOSSES {
+                    break;
+                }
+            }
+
+            set_threshold(element, operation, threshold);
+        }
+    }
+}
+
+/// Returns the fastest of several runs of `f`, each processing about [`CALIBRATION_ELEMENTS`] elements.
+fn measure(mut f: impl FnMut(), len: usize) -> Duration {
+    let iterations = CALIBRATION_ELEMENTS / len;
+
+    (0..CALIBRATION_ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                f();
+            }
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+/// Runs `$operation` on `$array` with the search kernels `$kernels`, searching for `$needle`. Operations that are
+/// only provided for some element types are passed as `Operation => field(arguments after the array)`.
+macro_rules! run_search {
+    ($kernels:expr, $array:expr, $needle:expr, $operation:expr $(, $extra:ident => $field:ident($($arg:expr),*))*) => {
+        match $operation {
+            Operation::Min => _ = black_box(($kernels.min)($array)),
+            Operation::Max => _ = black_box(($kernels.max)($array)),
+            Operation::MinMax => _ = black_box(($kernels.min_max)($array)),
+            Operation::Find => _ = black_box(($kernels.find)($array, $needle)),
+            Operation::Contains => _ = black_box(($kernels.contains)($array, $needle)),
+            Operation::Count => _ = black_box(($kernels.count)($array, $needle)),
+            $(Operation::$extra => _ = black_box(($kernels.$field)($array $(, $arg)*)),)*
+            // Not reachable for element types providing every operation.
+            #[allow(unreachable_patterns)]
+            operation => unreachable!("{operation:?} is not provided"),
+        }
+    };
+}
+
+/// Arrays the operations are measured with. Searched needles are never contained, so the whole array is processed.
+struct CalibrationData {
+    u8: Vec<u8>,
+    u16: Vec<u16>,
+    u32: Vec<u32>,
+    u64: Vec<u64>,
+    i8: Vec<i8>,
+    i16: Vec<i16>,
+    i32: Vec<i32>,
+    i64: Vec<i64>,
+    f32: Vec<f32>,
+    f64: Vec<f64>,
+    /// Arrays of equal items, which `AllEq` has to process completely.
+    uniform_u8: Vec<u8>,
+    uniform_u32: Vec<u32>,
+}
+
+impl CalibrationData {
+    fn new(len: usize) -> Self {
+        Self {
+            u8: (0..len).map(|i| (i % 200) as u8).collect(),
+            u16: (0..len).map(|i| i as u16).collect(),
+            u32: (0..len).map(|i| i as u32).collect(),
+            u64: (0..len).map(|i| i as u64).collect(),
+            i8: (0..len).map(|i| (i % 200) as i8).collect(),
+            i16: (0..len).map(|i| i as i16).collect(),
+            i32: (0..len).map(|i| i as i32).collect(),
+            i64: (0..len).map(|i| i as i64).collect(),
+            f32: (0..len).map(|i| i as f32).collect(),
+            f64: (0..len).map(|i| i as f64).collect(),
+            uniform_u8: vec![0; len],
+            uniform_u32: vec![0; len],
+        }
+    }
+
+    /// Runs `operation` on the first `len` elements of `element` using `kernels`.
+    fn run(&self, kernels: &Kernels, element: Element, operation: Operation, len: usize) {
+        match element {
+            Element::U8 if operation == Operation::AllEq => {
+                _ = black_box((kernels.u8.all_eq)(black_box(&self.uniform_u8[..len]), 0))
+            }
+            Element::U32 if operation == Operation::AllEq => {
+                _ = black_box((kernels.u32.all_eq)(black_box(&self.uniform_u32[..len]), 0))
+            }
+            Element::U8 => {
+                run_search!(
+                    kernels.u8,
+                    black_box(&self.u8[..len]),
+                    u8::MAX,
+                    operation,
+                    Sum => sum(),
+                    ArgMin => argmin(),
+                    ArgMax => argmax(),
+                    RFind => rfind(u8::MAX),
+                    FindAll => find_all(u8::MAX),
+                    FindAny2 => find_any2(u8::MAX, 254),
+                    FindAny3 => find_any3(u8::MAX, 254, 253),
+                    FindAnyOf => find_any_of(&[u8::MAX, 254, 253, 252]),
+                    FindSubslice => find_subslice(&[u8::MAX, 254], 1),
+                    Mismatch => mismatch(&self.u8[..len]),
+                    CountInRange => count_in_range(200, u8::MAX),
+                    FindInRange => find_in_range(200, u8::MAX)
+                )
+            }
+            Element::U16 => {
+                run_search!(
+                    kernels.u16,
+                    black_box(&self.u16[..len]),
+                    u16::MAX,
+                    operation
+                )
+            }
+            Element::U32 => {
+                run_search!(
+                    kernels.u32,
+                    black_box(&self.u32[..len]),
+                    u32::MAX,
+                    operation,
+                    Sum => sum(),
+                    ArgMin => argmin(),
+                    ArgMax => argmax(),
+                    RFind => rfind(u32::MAX),
+                    FindAll => find_all(u32::MAX),
+                    Mismatch => mismatch(&self.u32[..len]),
+                    CountInRange => count_in_range(u32::MAX - 1, u32::MAX),
+                    FindInRange => find_in_range(u32::MAX - 1, u32::MAX)
+                )
+            }
+            Element::U64 => {
+                run_search!(
+                    kernels.u64,
+                    black_box(&self.u64[..len]),
+                    u64::MAX,
+                    operation
+                )
+            }
+            Element::I8 => run_search!(kernels.i8, black_box(&self.i8[..len]), i8::MAX, operation),
+            Element::I16 => {
+                run_search!(
+                    kernels.i16,
+                    black_box(&self.i16[..len]),
+                    i16::MAX,
+                    operation
+                )
+            }
+            Element::I32 => {
+                run_search!(
+                    kernels.i32,
+                    black_box(&self.i32[..len]),
+                    i32::MAX,
+                    operation
+                )
+            }
+            Element::I64 => {
+                run_search!(
+                    kernels.i64,
+                    black_box(&self.i64[..len]),
+                    i64::MAX,
+                    operation
+                )
+            }
+            Element::F32 => {
+                let array = black_box(&self.f32[..len]);
+
+                match operation {
+                    Operation::Min => _ = black_box((kernels.f32.min)(array, NanMode::Ignore)),
+                    Operation::Max => _ = black_box((kernels.f32.max)(array, NanMode::Ignore)),
+                    Operation::MinMax => _ = black_box((kernels.f32.min_max)(array)),
+                    Operation::Sum => _ = black_box((kernels.f32.sum)(array, SumMode::Fast)),
+                    Operation::ArgMin => _ = black_box((kernels.f32.argmin)(array)),
+                    Operation::ArgMax => _ = black_box((kernels.f32.argmax)(array)),
+                    Operation::Mismatch => _ = black_box((kernels.f32.mismatch)(array, array)),
+                    Operation::CountInRange => {
+                        _ = black_box((kernels.f32.count_in_range)(array, -2.0, -1.0))
+                    }
+                    Operation::FindInRange => {
+                        _ = black_box((kernels.f32.find_in_range)(array, -2.0, -1.0))
+                    }
+                    _ => unreachable!("{operation:?} is not provided for {element:?}"),
+                }
+            }
+            Element::F64 => {
+                run_search!(kernels.f64, black_box(&self.f64[..len]), f64::MAX, operation, Sum => sum())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dispatch::table;
+    use crate::dispatch::test::LOCK;
+    use crate::float::test::{NAN_MODES, assert_same_f32};
+
+    /// Asserts that the search kernels `$kernels` return the same results as the original implementations.
+    macro_rules! assert_integer_kernels {
+        ($kernels:expr, $original:expr, $array:expr, $needle:expr) => {
+            let (kernels, original, array) = (&$kernels, &$original, $array);
+
+            assert_eq!((kernels.min)(array), (original.min)(array));
+            assert_eq!((kernels.max)(array), (original.max)(array));
+            assert_eq!((kernels.min_max)(array), (original.min_max)(array));
+            assert_eq!(
+                (kernels.find)(array, $needle),
+                (original.find)(array, $needle)
+            );
+            assert_eq!(
+                (kernels.contains)(array, $needle),
+                (original.contains)(array, $needle)
+            );
+            assert_eq!(
+                (kernels.count)(array, $needle),
+                (original.count)(array, $needle)
+            );
+        };
+    }
+
+    #[test]
+    fn test_set_threshold() {
+        let _lock = LOCK.lock().unwrap();
+
+        set_threshold(Element::U32, Operation::Find, 100);
+        assert_eq!(threshold(Element::U32, Operation::Find), 100);
+        assert_eq!(threshold(Element::U32, Operation::Min), DEFAULT_THRESHOLD);
+
+        set_threshold(Element::U32, Operation::Find, 0);
+        assert_eq!(threshold(Element::U32, Operation::Find), 1);
+
+        reset_thresholds();
+        assert_eq!(threshold(Element::U32, Operation::Find), DEFAULT_THRESHOLD);
+    }
+
+    /// Every kernel has to handle short arrays correctly, as the thresholds can be lowered to 1.
+    #[test]
+    fn test_small_thresholds() {
+        let _lock = LOCK.lock().unwrap();
+
+        for element in Element::ALL {
+            for &operation in element.operations() {
+                set_threshold(element, operation, 1);
+            }
+        }
+
+        let original = &scalar::KERNELS;
+
+        for backend in Backend::ALL.into_iter().filter(|b| b.is_supported()) {
+            let kernels = table(backend);
+
+            for len in 0..100 {
+                let values: Vec<i64> = (0..len).map(|i| i * 7919 % 1013 - 500).collect();
+
+                let u8: Vec<u8> = values.iter().map(|i| *i as u8).collect();
+                assert_integer_kernels!(kernels.u8, original.u8, &u8, 50);
+                assert_eq!((kernels.u8.argmin)(&u8), (original.u8.argmin)(&u8));
+                assert_eq!((kernels.u8.argmax)(&u8), (original.u8.argmax)(&u8));
+                assert_eq!(
+                    (kernels.u8.count_in_range)(&u8, 12, 50),
+                    (original.u8.count_in_range)(&u8, 12, 50)
+                );
+                assert_eq!(
+                    (kernels.u8.find_in_range)(&u8, 12, 50),
+                    (original.u8.find_in_range)(&u8, 12, 50)
+                );
+                assert_eq!((kernels.u8.all_eq)(&u8, 50), (original.u8.all_eq)(&u8, 50));
+                let uniform = vec![50; len as usize];
+                assert!((kernels.u8.all_eq)(&uniform, 50));
+                let reversed: Vec<u8> = u8.iter().rev().copied().collect();
+                assert_eq!(
+                    (kernels.u8.mismatch)(&u8, &reversed),
+                    (original.u8.mismatch)(&u8, &reversed)
+                );
+                assert_eq!((kernels.u8.sum)(&u8), (original.u8.sum)(&u8));
+                assert_eq!((kernels.u8.rfind)(&u8, 50), (original.u8.rfind)(&u8, 50));
+                assert_eq!(
+                    (kernels.u8.find_any2)(&u8, 50, 12),
+                    (original.u8.find_any2)(&u8, 50, 12)
+                );
+                assert_eq!(
+                    (kernels.u8.find_any3)(&u8, 50, 12, 200),
+                    (original.u8.find_any3)(&u8, 50, 12, 200)
+                );
+                assert_eq!(
+                    (kernels.u8.find_any_of)(&u8, &[50, 12, 200, 7]),
+                    (original.u8.find_any_of)(&u8, &[50, 12, 200, 7])
+                );
+                if len > 0 {
+                    let needle = &u8[len as usize / 2..];
+                    let needle = &needle[..needle.len().min(3)];
+                    assert_eq!(
+                        (kernels.u8.find_subslice)(&u8, needle, needle.len() - 1),
+                        (original.u8.find_subslice)(&u8, needle, needle.len() - 1)
+                    );
+                }
+                assert_eq!(
+                    (kernels.u8.find_all)(&u8, 50)
+                        .map(|(start, mask)| start + mask.trailing_zeros() as usize),
+                    (original.u8.find)(&u8, 50)
+                );
+
+                let u16: Vec<u16> = values.iter().map(|i| *i as u16).collect();
+                assert_integer_kernels!(kernels.u16, original.u16, &u16, 3);
+
+                let u32: Vec<u32> = values.iter().map(|i| *i as u32).collect();
+                assert_integer_kernels!(kernels.u32, original.u32, &u32, 3);
+                assert_eq!((kernels.u32.argmin)(&u32), (original.u32.argmin)(&u32));
+                assert_eq!((kernels.u32.argmax)(&u32), (original.u32.argmax)(&u32));
+                assert_eq!(
+                    (kernels.u32.count_in_range)(&u32, 3, 400),
+                    (original.u32.count_in_range)(&u32, 3, 400)
+                );
+                assert_eq!(
+                    (kernels.u32.find_in_range)(&u32, 3, 400),
+                    (original.u32.find_in_range)(&u32, 3, 400)
+                );
+                assert_eq!(
+                    (kernels.u32.all_eq)(&u32, 3),
+                    (original.u32.all_eq)(&u32, 3)
+                );
+                let uniform = vec![3; len as usize];
+                assert!((kernels.u32.all_eq)(&uniform, 3));
+                let reversed: Vec<u32> = u32.iter().rev().copied().collect();
+                assert_eq!(
+                    (kernels.u32.mismatch)(&u32, &reversed),
+                    (original.u32.mismatch)(&u32, &reversed)
+                );
+                assert_eq!((kernels.u32.sum)(&u32), (original.u32.sum)(&u32));
+                assert_eq!((kernels.u32.rfind)(&u32, 3), (original.u32.rfind)(&u32, 3));
+                assert_eq!(
+                    (kernels.u32.find_all)(&u32, 3)
+                        .map(|(start, mask)| start + mask.trailing_zeros() as usize),
+                    (original.u32.find)(&u32, 3)
+                );
+
+                let u64: Vec<u64> = values.iter().map(|i| *i as u64).collect();
+                assert_integer_kernels!(kernels.u64, original.u64, &u64, 3);
+
+                let i8: Vec<i8> = values.iter().map(|i| *i as i8).collect();
+                assert_integer_kernels!(kernels.i8, original.i8, &i8, -3);
+
+                let i16: Vec<i16> = values.iter().map(|i| *i as i16).collect();
+                assert_integer_kernels!(kernels.i16, original.i16, &i16, -3);
+
+                let i32: Vec<i32> = values.iter().map(|i| *i as i32).collect();
+                assert_integer_kernels!(kernels.i32, original.i32, &i32, -3);
+
+                assert_integer_kernels!(kernels.i64, original.i64, &values, -3);
+
+                let mut f32: Vec<f32> = values.iter().map(|i| *i as f32).collect();
+                assert_eq!((kernels.f32.argmin)(&f32), (original.f32.argmin)(&f32));
+                assert_eq!((kernels.f32.argmax)(&f32), (original.f32.argmax)(&f32));
+                assert_eq!(
+                    (kernels.f32.count_in_range)(&f32, -30.0, 70.5),
+                    (original.f32.count_in_range)(&f32, -30.0, 70.5)
+                );
+                assert_eq!(
+                    (kernels.f32.find_in_range)(&f32, -30.0, 70.5),
+                    (original.f32.find_in_range)(&f32, -30.0, 70.5)
+                );
+                let reversed: Vec<f32> = f32.iter().rev().copied().collect();
+                assert_eq!(
+                    (kernels.f32.mismatch)(&f32, &reversed),
+                    (original.f32.mismatch)(&f32, &reversed)
+                );
+                // The items are small integers, so every summation order is exact.
+                for mode in [SumMode::Fast, SumMode::Kahan] {
+                    assert_eq!(
+                        (kernels.f32.sum)(&f32, mode),
+                        (original.f32.sum)(&f32, mode)
+                    );
+                }
+                for mode in NAN_MODES {
+                    assert_same_f32(
+                        (kernels.f32.min)(&f32, mode),
+                        (original.f32.min)(&f32, mode),
+                    );
+                    assert_same_f32(
+                        (kernels.f32.max)(&f32, mode),
+                        (original.f32.max)(&f32, mode),
+                    );
+                }
+                assert_eq!(
+                    (kernels.f32.min_max)(&f32).map(|(min, max)| (min.to_bits(), max.to_bits())),
+                    (original.f32.min_max)(&f32).map(|(min, max)| (min.to_bits(), max.to_bits()))
+                );
+
+                if let Some(last) = f32.last_mut() {
+                    *last = f32::NAN;
+                }
+                for mode in NAN_MODES {
+                    assert_same_f32(
+                        (kernels.f32.min)(&f32, mode),
+                        (original.f32.min)(&f32, mode),
+                    );
+                    assert_same_f32(
+                        (kernels.f32.max)(&f32, mode),
+                        (original.f32.max)(&f32, mode),
+                    );
+                }
+                assert_eq!(
+                    (kernels.f32.min_max)(&f32).map(|(min, max)| (min.to_bits(), max.to_bits())),
+                    (original.f32.min_max)(&f32).map(|(min, max)| (min.to_bits(), max.to_bits()))
+                );
+
+                let f64: Vec<f64> = values.iter().map(|i| *i as f64).collect();
+                assert_integer_kernels!(kernels.f64, original.f64, &f64, -3.0);
+                assert_eq!((kernels.f64.sum)(&f64), (original.f64.sum)(&f64));
+            }
+        }
+
+        reset_thresholds();
+    }
+    #[test]
+    fn test_calibrate() {
+        let _lock = LOCK.lock().unwrap();
+
+        calibrate();
+
+        for element in Element::ALL {
+            for &operation in element.operations() {
+                let threshold = threshold(element, operation);
+                assert!(CALIBRATION_LENGTHS.contains(&threshold));
+
+                if kernels().backend == Backend::Scalar {
+                    assert_eq!(threshold, DEFAULT_THRESHOLD);
+                }
+            }
+        }
+
+        reset_thresholds();
+    }
+}
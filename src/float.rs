@@ -0,0 +1,271 @@
+/// How `min_simd_with` and `max_simd_with` of [`ArrayF32SimdExt`](crate::traits::array_f32::ArrayF32SimdExt) handle
+/// NaN values.
+///
+/// All modes order `-0.0` before `0.0` and return the same result on every backend and for every array length.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NanMode {
+    /// NaN values are skipped, like [`f32::min`] and [`f32::max`] do. The result is only NaN if all items are NaN,
+    /// in which case the first item is returned.
+    #[default]
+    Ignore,
+    /// Any NaN value makes the result NaN, like `minimum` and `maximum` of IEEE 754-2019 do. The first NaN of the
+    /// array is returned.
+    Propagate,
+    /// Items are ordered by [`f32::total_cmp`], so negative NaNs are the smallest and positive NaNs the largest values.
+    TotalOrder,
+}
+
+/// How `sum_simd_with` of [`ArrayF32SimdExt`](crate::traits::array_f32::ArrayF32SimdExt) adds up the items.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SumMode {
+    /// Items are added to several partial sums, which are added up at the end. This is the fastest mode and usually
+    /// more accurate than a sequential loop, but the rounding error still grows with the length of the array.
+    #[default]
+    Fast,
+    /// The rounding error of every addition is tracked and added to the next item, as done by Kahan summation. The
+    /// error doesn't grow with the length of the array, at about twice the cost of [`SumMode::Fast`].
+    Kahan,
+}
+
+/// A sum whose rounding errors are tracked by Kahan summation.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct KahanSum {
+    sum: f32,
+    /// The negated error of `sum`, which is subtracted from the next item.
+    compensation: f32,
+}
+
+impl KahanSum {
+    #[inline]
+    pub(crate) fn add(&mut self, item: f32) {
+        let corrected = item - self.compensation;
+        let sum = self.sum + corrected;
+
+        // Once the sum is infinite or NaN, the error can't be determined and doesn't matter anymore.
+        self.compensation = if sum.is_finite() {
+            (sum - self.sum) - corrected
+        } else {
+            0.0
+        };
+
+        self.sum = sum;
+    }
+
+    /// Returns the sum with the remaining error added back.
+    #[inline]
+    pub(crate) fn total(self) -> f32 {
+        self.sum - self.compensation
+    }
+}
+
+/// Maps the bits of `value` to an integer that is ordered like [`f32::total_cmp`]. The mapping is its own inverse.
+#[inline]
+pub(crate) fn total_order_key(value: f32) -> i32 {
+    let bits = value.to_bits() as i32;
+    bits ^ (((bits >> 31) as u32) >> 1) as i32
+}
+
+/// Reverts [`total_order_key`].
+#[inline]
+pub(crate) fn from_total_order_key(key: i32) -> f32 {
+    f32::from_bits((key ^ (((key >> 31) as u32) >> 1) as i32) as u32)
+}
+
+/// Maps the bits of `value` to an integer that is ordered like [`f64::total_cmp`]. The mapping is its own inverse.
+#[inline]
+pub(crate) fn total_order_key_f64(value: f64) -> i64 {
+    let bits = value.to_bits() as i64;
+    bits ^ (((bits >> 63) as u64) >> 1) as i64
+}
+
+/// Reverts [`total_order_key_f64`].
+#[inline]
+pub(crate) fn from_total_order_key_f64(key: i64) -> f64 {
+    f64::from_bits((key ^ (((key >> 63) as u64) >> 1) as i64) as u64)
+}
+
+/// Finishes a vectorized minimum or maximum of `array`, whose first `vectorized_part` items have been reduced to the
+/// total order `key`. Vectorized kernels start with `i32::MAX` as minimum or `i32::MIN` as maximum, keep their key in
+/// [`NanMode::Ignore`] for NaN items and have to set `nan_seen` if there were any.
+///
+/// Reduces the remaining items and applies the NaN handling of `mode`, so all kernels produce identical results.
+#[inline]
+pub(crate) fn finish_min_max_f32(
+    array: &[f32],
+    vectorized_part: usize,
+    mut key: i32,
+    mut nan_seen: bool,
+    max: bool,
+    mode: NanMode,
+) -> Option<f32> {
+    if array.is_empty() {
+        return None;
+    }
+
+    for &item in &array[vectorized_part..] {
+        if item.is_nan() {
+            nan_seen = true;
+
+            if mode == NanMode::Ignore {
+                continue;
+            }
+        }
+
+        key = if max {
+            key.max(total_order_key(item))
+        } else {
+            key.min(total_order_key(item))
+        };
+    }
+
+    match mode {
+        NanMode::Propagate if nan_seen => array.iter().copied().find(|i| i.is_nan()),
+        NanMode::Ignore if nan_seen && array.iter().all(|i| i.is_nan()) => Some(array[0]),
+        _ => Some(from_total_order_key(key)),
+    }
+}
+
+/// Finishes a vectorized minimum or maximum of `array` like [`finish_min_max_f32`] does for [`NanMode::Ignore`], which
+/// is the only mode of `f64`. Vectorized kernels start with `i64::MAX` as minimum or `i64::MIN` as maximum and keep
+/// their key for NaN items. Both are keys of NaNs, so they only remain if all items are NaN.
+#[inline]
+pub(crate) fn finish_min_max_f64(
+    array: &[f64],
+    vectorized_part: usize,
+    mut key: i64,
+    max: bool,
+) -> Option<f64> {
+    let first = *array.first()?;
+
+    for &item in array[vectorized_part..].iter().filter(|i| !i.is_nan()) {
+        key = if max {
+            key.max(total_order_key_f64(item))
+        } else {
+            key.min(total_order_key_f64(item))
+        };
+    }
+
+    match key {
+        i64::MIN | i64::MAX => Some(first),
+        _ => Some(from_total_order_key_f64(key)),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use rand::rngs::StdRng;
+    use rand::{Rng, RngCore, SeedableRng};
+
+    use super::*;
+
+    pub(crate) const NAN_MODES: [NanMode; 3] =
+        [NanMode::Ignore, NanMode::Propagate, NanMode::TotalOrder];
+
+    /// Returns random floats where roughly every `1 / special_rate` item is a NaN of either sign, a signed zero or an
+    /// infinity.
+    pub(crate) fn random_f32_with_specials(
+        rng: &mut impl Rng,
+        len: usize,
+        special_rate: u32,
+    ) -> Vec<f32> {
+        const SPECIALS: [f32; 6] = [
+            f32::NAN,
+            -f32::NAN,
+            0.0,
+            -0.0,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ];
+
+        (0..len)
+            .map(|_| {
+                if rng.next_u32().is_multiple_of(special_rate) {
+                    SPECIALS[rng.next_u32() as usize % SPECIALS.len()]
+                } else {
+                    rng.next_u32() as i32 as f32 / 102.0
+                }
+            })
+            .collect()
+    }
+
+    /// Asserts that two results are bitwise identical, which `assert_eq` can't do for NaN.
+    #[track_caller]
+    pub(crate) fn assert_same_f32(actual: Option<f32>, expected: Option<f32>) {
+        assert_eq!(
+            actual.map(f32::to_bits),
+            expected.map(f32::to_bits),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_total_order_key() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let mut values = random_f32_with_specials(&mut rng, 1000, 4);
+        values.extend((0..1000).map(|_| f32::from_bits(rng.next_u32())));
+
+        for a in values.iter().step_by(7) {
+            assert_eq!(
+                from_total_order_key(total_order_key(*a)).to_bits(),
+                a.to_bits()
+            );
+
+            for b in values.iter().step_by(11) {
+                assert_eq!(
+                    total_order_key(*a).cmp(&total_order_key(*b)),
+                    a.total_cmp(b)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_kahan_sum() {
+        let mut sum = KahanSum::default();
+        sum.add(1.0);
+        for _ in 0..10_000 {
+            sum.add(1e-8);
+        }
+        assert_eq!(sum.total(), 1.0001);
+
+        let mut sum = KahanSum::default();
+        for item in [1.0, f32::INFINITY, 2.0] {
+            sum.add(item);
+        }
+        assert_eq!(sum.total(), f32::INFINITY);
+
+        sum.add(f32::NEG_INFINITY);
+        assert!(sum.total().is_nan());
+    }
+
+    #[test]
+    fn test_finish_min_max_f32() {
+        let array = [1.0, f32::NAN, -0.0, 0.0];
+        let key = total_order_key(1.0);
+
+        assert_same_f32(
+            finish_min_max_f32(&array, 2, key, true, false, NanMode::Ignore),
+            Some(-0.0),
+        );
+        assert_same_f32(
+            finish_min_max_f32(&array, 2, key, true, true, NanMode::Ignore),
+            Some(1.0),
+        );
+        assert!(
+            finish_min_max_f32(&array, 2, key, true, false, NanMode::Propagate)
+                .unwrap()
+                .is_nan()
+        );
+
+        let nans = [-f32::NAN, f32::NAN];
+        assert_same_f32(
+            finish_min_max_f32(&nans, 0, i32::MAX, false, false, NanMode::Ignore),
+            Some(-f32::NAN),
+        );
+        assert_same_f32(
+            finish_min_max_f32(&[], 0, i32::MAX, false, false, NanMode::Ignore),
+            None,
+        );
+    }
+}
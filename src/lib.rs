@@ -1,9 +1,20 @@
 #![allow(clippy::missing_safety_doc)]
 
+/// Runtime selection of the best implementation for the running CPU.
+pub(crate) mod dispatch;
+/// NaN handling and summation modes of float operations.
+pub(crate) mod float;
 /// Original implementations of the algorithms.
 pub(crate) mod original;
 pub mod prelude;
 pub mod traits;
 
+pub use dispatch::{
+    BACKEND_ENV, Backend, BackendError, Capabilities, DEFAULT_THRESHOLD, Element, Operation,
+    active_backend, calibrate, capabilities, reset_backend, reset_thresholds, set_backend,
+    set_threshold, threshold,
+};
+pub use float::{NanMode, SumMode};
+
 #[cfg(target_arch = "x86_64")]
 pub(crate) mod x86_64;
@@ -1,3 +1,7 @@
+use std::cmp::{max_by, min_by};
+
+use crate::float::{KahanSum, NanMode, SumMode};
+
 #[inline]
 pub(crate) fn find_iter_u32(array: &[u32], needle: u32) -> Option<usize> {
     array.iter().position(|i| *i == needle)
@@ -8,14 +12,147 @@ pub(crate) fn find_iter_u8(array: &[u8], needle: u8) -> Option<usize> {
     array.iter().position(|i| *i == needle)
 }
 
+#[inline]
+pub(crate) fn rfind_iter_u32(array: &[u32], needle: u32) -> Option<usize> {
+    array.iter().rposition(|i| *i == needle)
+}
+
+#[inline]
+pub(crate) fn rfind_iter_u8(array: &[u8], needle: u8) -> Option<usize> {
+    array.iter().rposition(|i| *i == needle)
+}
+
+/// Returns the position of the first occurrence of `needle` together with the mask of its occurrences among the 64
+/// items starting there, which is the block `find_all_simd` continues from.
+#[inline]
+pub(crate) fn find_all_iter_u32(array: &[u32], needle: u32) -> Option<(usize, u64)> {
+    let start = find_iter_u32(array, needle)?;
+    Some((start, block_mask(&array[start..], needle)))
+}
+
+/// Returns the position of the first occurrence of `needle` together with the mask of its occurrences among the 64
+/// items starting there, which is the block `find_all_simd` continues from.
+#[inline]
+pub(crate) fn find_all_iter_u8(array: &[u8], needle: u8) -> Option<(usize, u64)> {
+    let start = find_iter_u8(array, needle)?;
+    Some((start, block_mask(&array[start..], needle)))
+}
+
+/// Returns a mask whose bit `i` is set if the item at position `i` is `needle`, for the first 64 items of the array.
+#[inline]
+fn block_mask<T: Copy + PartialEq>(array: &[T], needle: T) -> u64 {
+    array
+        .iter()
+        .take(64)
+        .enumerate()
+        .filter(|(_, item)| **item == needle)
+        .fold(0, |mask, (i, _)| mask | 1 << i)
+}
+
+#[inline]
+pub(crate) fn find_any2_iter_u8(array: &[u8], a: u8, b: u8) -> Option<usize> {
+    array.iter().position(|i| *i == a || *i == b)
+}
+
+#[inline]
+pub(crate) fn find_any3_iter_u8(array: &[u8], a: u8, b: u8, c: u8) -> Option<usize> {
+    array.iter().position(|i| *i == a || *i == b || *i == c)
+}
+
+#[inline]
+pub(crate) fn find_any_of_iter_u8(array: &[u8], needles: &[u8]) -> Option<usize> {
+    let mut set = [false; 256];
+    for needle in needles {
+        set[*needle as usize] = true;
+    }
+
+    array.iter().position(|i| set[*i as usize])
+}
+
+/// Returns the position of the first occurrence of the non-empty `needle`, comparing the whole window only if its
+/// first byte and the byte at `probe` match.
+#[inline]
+pub(crate) fn find_subslice_iter_u8(haystack: &[u8], needle: &[u8], probe: usize) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| {
+        window[0] == needle[0] && window[probe] == needle[probe] && window == needle
+    })
+}
+
+#[inline]
+pub(crate) fn mismatch_iter_u32(array: &[u32], other: &[u32]) -> Option<usize> {
+    array
+        .iter()
+        .zip(other)
+        .position(|(a, b)| a != b)
+        .or_else(|| (array.len() != other.len()).then(|| array.len().min(other.len())))
+}
+
+#[inline]
+pub(crate) fn mismatch_iter_u8(array: &[u8], other: &[u8]) -> Option<usize> {
+    array
+        .iter()
+        .zip(other)
+        .position(|(a, b)| a != b)
+        .or_else(|| (array.len() != other.len()).then(|| array.len().min(other.len())))
+}
+
+#[inline]
+pub(crate) fn mismatch_iter_f32(array: &[f32], other: &[f32]) -> Option<usize> {
+    array
+        .iter()
+        .zip(other)
+        .position(|(a, b)| a != b)
+        .or_else(|| (array.len() != other.len()).then(|| array.len().min(other.len())))
+}
+
+#[inline]
+pub(crate) fn count_in_range_iter_u32(array: &[u32], lo: u32, hi: u32) -> usize {
+    array.iter().filter(|i| (lo..=hi).contains(*i)).count()
+}
+
+#[inline]
+pub(crate) fn find_in_range_iter_u32(array: &[u32], lo: u32, hi: u32) -> Option<usize> {
+    array.iter().position(|i| (lo..=hi).contains(i))
+}
+
+#[inline]
+pub(crate) fn count_in_range_iter_u8(array: &[u8], lo: u8, hi: u8) -> usize {
+    array.iter().filter(|i| (lo..=hi).contains(*i)).count()
+}
+
+#[inline]
+pub(crate) fn find_in_range_iter_u8(array: &[u8], lo: u8, hi: u8) -> Option<usize> {
+    array.iter().position(|i| (lo..=hi).contains(i))
+}
+
+#[inline]
+pub(crate) fn count_in_range_iter_f32(array: &[f32], lo: f32, hi: f32) -> usize {
+    array.iter().filter(|i| (lo..=hi).contains(*i)).count()
+}
+
+#[inline]
+pub(crate) fn find_in_range_iter_f32(array: &[f32], lo: f32, hi: f32) -> Option<usize> {
+    array.iter().position(|i| (lo..=hi).contains(i))
+}
+
+#[inline]
+pub(crate) fn all_eq_iter_u32(array: &[u32], value: u32) -> bool {
+    array.iter().all(|i| *i == value)
+}
+
+#[inline]
+pub(crate) fn all_eq_iter_u8(array: &[u8], value: u8) -> bool {
+    array.iter().all(|i| *i == value)
+}
+
 #[inline]
 pub(crate) fn contains_iter_u32(array: &[u32], needle: u32) -> bool {
-    array.iter().any(|i| *i == needle)
+    array.contains(&needle)
 }
 
 #[inline]
 pub(crate) fn contains_iter_u8(array: &[u8], needle: u8) -> bool {
-    array.iter().any(|i| *i == needle)
+    array.contains(&needle)
 }
 
 #[inline]
@@ -23,6 +160,11 @@ pub(crate) fn count_iter_u32(array: &[u32], needle: u32) -> usize {
     array.iter().filter(|i| **i == needle).count()
 }
 
+#[inline]
+pub(crate) fn count_iter_u8(array: &[u8], needle: u8) -> usize {
+    array.iter().filter(|i| **i == needle).count()
+}
+
 #[inline]
 pub(crate) fn min_iter_u8(array: &[u8]) -> Option<u8> {
     array.iter().min().copied()
@@ -38,43 +180,491 @@ pub(crate) fn max_iter_u32(array: &[u32]) -> Option<u32> {
     array.iter().max().copied()
 }
 
+#[inline]
+pub(crate) fn min_max_iter_u32(array: &[u32]) -> Option<(u32, u32)> {
+    let first = *array.first()?;
+
+    Some(
+        array
+            .iter()
+            .fold((first, first), |(min, max), i| (min.min(*i), max.max(*i))),
+    )
+}
+
+#[inline]
+pub(crate) fn sum_iter_u32(array: &[u32]) -> u64 {
+    array.iter().map(|i| *i as u64).sum()
+}
+
 #[inline]
 pub(crate) fn max_iter_u8(array: &[u8]) -> Option<u8> {
     array.iter().max().copied()
 }
 
 #[inline]
-pub(crate) fn max_iter_f32(array: &[f32]) -> Option<f32> {
-    if array.is_empty() {
-        return None;
-    }
+pub(crate) fn min_max_iter_u8(array: &[u8]) -> Option<(u8, u8)> {
+    let first = *array.first()?;
 
-    let mut max = array[0];
+    Some(
+        array
+            .iter()
+            .fold((first, first), |(min, max), i| (min.min(*i), max.max(*i))),
+    )
+}
 
-    for i in &array[1..] {
-        if *i > max {
-            max = *i;
+#[inline]
+pub(crate) fn sum_iter_u8(array: &[u8]) -> u64 {
+    array.iter().map(|i| *i as u64).sum()
+}
+
+#[inline]
+pub(crate) fn max_iter_f32(array: &[f32], mode: NanMode) -> Option<f32> {
+    let first = *array.first()?;
+
+    let max = match mode {
+        NanMode::Ignore => array
+            .iter()
+            .copied()
+            .filter(|i| !i.is_nan())
+            .max_by(f32::total_cmp)
+            .unwrap_or(first),
+        NanMode::Propagate => match array.iter().copied().find(|i| i.is_nan()) {
+            Some(nan) => nan,
+            None => array
+                .iter()
+                .copied()
+                .max_by(f32::total_cmp)
+                .unwrap_or(first),
+        },
+        NanMode::TotalOrder => array
+            .iter()
+            .copied()
+            .max_by(f32::total_cmp)
+            .unwrap_or(first),
+    };
+
+    Some(max)
+}
+
+#[inline]
+pub(crate) fn min_iter_f32(array: &[f32], mode: NanMode) -> Option<f32> {
+    let first = *array.first()?;
+
+    let min = match mode {
+        NanMode::Ignore => array
+            .iter()
+            .copied()
+            .filter(|i| !i.is_nan())
+            .min_by(f32::total_cmp)
+            .unwrap_or(first),
+        NanMode::Propagate => match array.iter().copied().find(|i| i.is_nan()) {
+            Some(nan) => nan,
+            None => array
+                .iter()
+                .copied()
+                .min_by(f32::total_cmp)
+                .unwrap_or(first),
+        },
+        NanMode::TotalOrder => array
+            .iter()
+            .copied()
+            .min_by(f32::total_cmp)
+            .unwrap_or(first),
+    };
+
+    Some(min)
+}
+
+/// Returns the minimum and maximum like [`min_iter_f32`] and [`max_iter_f32`] do with [`NanMode::Ignore`].
+#[inline]
+pub(crate) fn min_max_iter_f32(array: &[f32]) -> Option<(f32, f32)> {
+    let first = *array.first()?;
+
+    let mut items = array.iter().copied().filter(|i| !i.is_nan());
+
+    let Some(start) = items.next() else {
+        return Some((first, first));
+    };
+
+    Some(items.fold((start, start), |(min, max), i| {
+        (
+            min_by(min, i, f32::total_cmp),
+            max_by(max, i, f32::total_cmp),
+        )
+    }))
+}
+
+#[inline]
+pub(crate) fn sum_iter_f32(array: &[f32], mode: SumMode) -> f32 {
+    match mode {
+        SumMode::Fast => array.iter().sum(),
+        SumMode::Kahan => {
+            let mut sum = KahanSum::default();
+            for item in array {
+                sum.add(*item);
+            }
+            sum.total()
         }
     }
+}
+
+/// Returns the largest item like [`max_iter_f32`] does with [`NanMode::Ignore`].
+#[inline]
+pub(crate) fn max_iter_f64(array: &[f64]) -> Option<f64> {
+    let first = *array.first()?;
+
+    let max = array
+        .iter()
+        .copied()
+        .filter(|i| !i.is_nan())
+        .max_by(f64::total_cmp)
+        .unwrap_or(first);
 
     Some(max)
 }
 
+/// Returns the smallest item like [`min_iter_f32`] does with [`NanMode::Ignore`].
+#[inline]
+pub(crate) fn min_iter_f64(array: &[f64]) -> Option<f64> {
+    let first = *array.first()?;
+
+    let min = array
+        .iter()
+        .copied()
+        .filter(|i| !i.is_nan())
+        .min_by(f64::total_cmp)
+        .unwrap_or(first);
+
+    Some(min)
+}
+
+/// Returns the minimum and maximum like [`min_iter_f64`] and [`max_iter_f64`] do.
+#[inline]
+pub(crate) fn min_max_iter_f64(array: &[f64]) -> Option<(f64, f64)> {
+    let first = *array.first()?;
+
+    let mut items = array.iter().copied().filter(|i| !i.is_nan());
+
+    let Some(start) = items.next() else {
+        return Some((first, first));
+    };
+
+    Some(items.fold((start, start), |(min, max), i| {
+        (
+            min_by(min, i, f64::total_cmp),
+            max_by(max, i, f64::total_cmp),
+        )
+    }))
+}
+
 #[inline]
-pub(crate) fn min_iter_f32(array: &[f32]) -> Option<f32> {
+pub(crate) fn find_iter_f64(array: &[f64], needle: f64) -> Option<usize> {
+    array.iter().position(|i| *i == needle)
+}
+
+#[inline]
+pub(crate) fn contains_iter_f64(array: &[f64], needle: f64) -> bool {
+    array.contains(&needle)
+}
+
+#[inline]
+pub(crate) fn count_iter_f64(array: &[f64], needle: f64) -> usize {
+    array.iter().filter(|i| **i == needle).count()
+}
+
+#[inline]
+pub(crate) fn sum_iter_f64(array: &[f64]) -> f64 {
+    array.iter().sum()
+}
+
+#[inline]
+pub(crate) fn find_iter_u16(array: &[u16], needle: u16) -> Option<usize> {
+    array.iter().position(|i| *i == needle)
+}
+
+#[inline]
+pub(crate) fn contains_iter_u16(array: &[u16], needle: u16) -> bool {
+    array.contains(&needle)
+}
+
+#[inline]
+pub(crate) fn count_iter_u16(array: &[u16], needle: u16) -> usize {
+    array.iter().filter(|i| **i == needle).count()
+}
+
+#[inline]
+pub(crate) fn min_iter_u16(array: &[u16]) -> Option<u16> {
+    array.iter().min().copied()
+}
+
+#[inline]
+pub(crate) fn max_iter_u16(array: &[u16]) -> Option<u16> {
+    array.iter().max().copied()
+}
+
+#[inline]
+pub(crate) fn min_max_iter_u16(array: &[u16]) -> Option<(u16, u16)> {
+    let first = *array.first()?;
+
+    Some(
+        array
+            .iter()
+            .fold((first, first), |(min, max), i| (min.min(*i), max.max(*i))),
+    )
+}
+
+#[inline]
+pub(crate) fn find_iter_u64(array: &[u64], needle: u64) -> Option<usize> {
+    array.iter().position(|i| *i == needle)
+}
+
+#[inline]
+pub(crate) fn contains_iter_u64(array: &[u64], needle: u64) -> bool {
+    array.contains(&needle)
+}
+
+#[inline]
+pub(crate) fn count_iter_u64(array: &[u64], needle: u64) -> usize {
+    array.iter().filter(|i| **i == needle).count()
+}
+
+#[inline]
+pub(crate) fn min_iter_u64(array: &[u64]) -> Option<u64> {
+    array.iter().min().copied()
+}
+
+#[inline]
+pub(crate) fn max_iter_u64(array: &[u64]) -> Option<u64> {
+    array.iter().max().copied()
+}
+
+#[inline]
+pub(crate) fn min_max_iter_u64(array: &[u64]) -> Option<(u64, u64)> {
+    let first = *array.first()?;
+
+    Some(
+        array
+            .iter()
+            .fold((first, first), |(min, max), i| (min.min(*i), max.max(*i))),
+    )
+}
+
+#[inline]
+pub(crate) fn find_iter_i8(array: &[i8], needle: i8) -> Option<usize> {
+    array.iter().position(|i| *i == needle)
+}
+
+#[inline]
+pub(crate) fn contains_iter_i8(array: &[i8], needle: i8) -> bool {
+    array.contains(&needle)
+}
+
+#[inline]
+pub(crate) fn count_iter_i8(array: &[i8], needle: i8) -> usize {
+    array.iter().filter(|i| **i == needle).count()
+}
+
+#[inline]
+pub(crate) fn min_iter_i8(array: &[i8]) -> Option<i8> {
+    array.iter().min().copied()
+}
+
+#[inline]
+pub(crate) fn max_iter_i8(array: &[i8]) -> Option<i8> {
+    array.iter().max().copied()
+}
+
+#[inline]
+pub(crate) fn min_max_iter_i8(array: &[i8]) -> Option<(i8, i8)> {
+    let first = *array.first()?;
+
+    Some(
+        array
+            .iter()
+            .fold((first, first), |(min, max), i| (min.min(*i), max.max(*i))),
+    )
+}
+
+#[inline]
+pub(crate) fn find_iter_i16(array: &[i16], needle: i16) -> Option<usize> {
+    array.iter().position(|i| *i == needle)
+}
+
+#[inline]
+pub(crate) fn contains_iter_i16(array: &[i16], needle: i16) -> bool {
+    array.contains(&needle)
+}
+
+#[inline]
+pub(crate) fn count_iter_i16(array: &[i16], needle: i16) -> usize {
+    array.iter().filter(|i| **i == needle).count()
+}
+
+#[inline]
+pub(crate) fn min_iter_i16(array: &[i16]) -> Option<i16> {
+    array.iter().min().copied()
+}
+
+#[inline]
+pub(crate) fn max_iter_i16(array: &[i16]) -> Option<i16> {
+    array.iter().max().copied()
+}
+
+#[inline]
+pub(crate) fn min_max_iter_i16(array: &[i16]) -> Option<(i16, i16)> {
+    let first = *array.first()?;
+
+    Some(
+        array
+            .iter()
+            .fold((first, first), |(min, max), i| (min.min(*i), max.max(*i))),
+    )
+}
+
+#[inline]
+pub(crate) fn find_iter_i32(array: &[i32], needle: i32) -> Option<usize> {
+    array.iter().position(|i| *i == needle)
+}
+
+#[inline]
+pub(crate) fn contains_iter_i32(array: &[i32], needle: i32) -> bool {
+    array.contains(&needle)
+}
+
+#[inline]
+pub(crate) fn count_iter_i32(array: &[i32], needle: i32) -> usize {
+    array.iter().filter(|i| **i == needle).count()
+}
+
+#[inline]
+pub(crate) fn min_iter_i32(array: &[i32]) -> Option<i32> {
+    array.iter().min().copied()
+}
+
+#[inline]
+pub(crate) fn max_iter_i32(array: &[i32]) -> Option<i32> {
+    array.iter().max().copied()
+}
+
+#[inline]
+pub(crate) fn min_max_iter_i32(array: &[i32]) -> Option<(i32, i32)> {
+    let first = *array.first()?;
+
+    Some(
+        array
+            .iter()
+            .fold((first, first), |(min, max), i| (min.min(*i), max.max(*i))),
+    )
+}
+
+#[inline]
+pub(crate) fn find_iter_i64(array: &[i64], needle: i64) -> Option<usize> {
+    array.iter().position(|i| *i == needle)
+}
+
+#[inline]
+pub(crate) fn contains_iter_i64(array: &[i64], needle: i64) -> bool {
+    array.contains(&needle)
+}
+
+#[inline]
+pub(crate) fn count_iter_i64(array: &[i64], needle: i64) -> usize {
+    array.iter().filter(|i| **i == needle).count()
+}
+
+#[inline]
+pub(crate) fn min_iter_i64(array: &[i64]) -> Option<i64> {
+    array.iter().min().copied()
+}
+
+#[inline]
+pub(crate) fn max_iter_i64(array: &[i64]) -> Option<i64> {
+    array.iter().max().copied()
+}
+
+#[inline]
+pub(crate) fn min_max_iter_i64(array: &[i64]) -> Option<(i64, i64)> {
+    let first = *array.first()?;
+
+    Some(
+        array
+            .iter()
+            .fold((first, first), |(min, max), i| (min.min(*i), max.max(*i))),
+    )
+}
+
+#[inline]
+pub(crate) fn argmin_iter_u8(array: &[u8]) -> Option<usize> {
+    array
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, i)| **i)
+        .map(|(index, _)| index)
+}
+
+#[inline]
+pub(crate) fn argmax_iter_u8(array: &[u8]) -> Option<usize> {
+    // Reversed, as `max_by_key` returns the last of several maximums.
+    array
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, i)| **i)
+        .map(|(index, _)| index)
+}
+
+#[inline]
+pub(crate) fn argmin_iter_u32(array: &[u32]) -> Option<usize> {
+    array
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, i)| **i)
+        .map(|(index, _)| index)
+}
+
+#[inline]
+pub(crate) fn argmax_iter_u32(array: &[u32]) -> Option<usize> {
+    // Reversed, as `max_by_key` returns the last of several maximums.
+    array
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, i)| **i)
+        .map(|(index, _)| index)
+}
+
+#[inline]
+pub(crate) fn argmin_iter_f32(array: &[f32]) -> Option<usize> {
     if array.is_empty() {
         return None;
     }
 
-    let mut min = array[0];
+    let index = array
+        .iter()
+        .enumerate()
+        .filter(|(_, i)| !i.is_nan())
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(index, _)| index);
 
-    for i in &array[1..] {
-        if *i < min {
-            min = *i;
-        }
+    Some(index)
+}
+
+#[inline]
+pub(crate) fn argmax_iter_f32(array: &[f32]) -> Option<usize> {
+    if array.is_empty() {
+        return None;
     }
 
-    Some(min)
+    // Reversed, as `max_by` returns the last of several maximums.
+    let index = array
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, i)| !i.is_nan())
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(index, _)| index);
+
+    Some(index)
 }
 
 #[cfg(test)]
@@ -92,14 +682,115 @@ mod test {
             for _ in 0..10 {
                 let vec: Vec<_> = (0..len).map(|_| rng.next_u32() as f32 / 102.0).collect();
 
-                let our_min = min_iter_f32(&vec);
+                let our_min = min_iter_f32(&vec, NanMode::Ignore);
                 let real_min = vec.iter().map(|i| OrderedFloat(*i)).min().map(|i| *i);
                 assert_eq!(our_min, real_min);
 
-                let our_max = max_iter_f32(&vec);
+                let our_max = max_iter_f32(&vec, NanMode::Ignore);
                 let real_max = vec.iter().map(|i| OrderedFloat(*i)).max().map(|i| *i);
                 assert_eq!(our_max, real_max);
             }
         }
     }
+
+    #[test]
+    fn test_min_max_iter_f32_nan_modes() {
+        let vec = [1.0, f32::NAN, -0.0, f32::INFINITY, 0.0, -f32::NAN, -2.0];
+
+        let bits = |value: Option<f32>| value.map(f32::to_bits);
+
+        assert_eq!(bits(min_iter_f32(&vec, NanMode::Ignore)), bits(Some(-2.0)));
+        assert_eq!(
+            bits(max_iter_f32(&vec, NanMode::Ignore)),
+            bits(Some(f32::INFINITY))
+        );
+
+        assert_eq!(
+            bits(min_iter_f32(&vec, NanMode::Propagate)),
+            bits(Some(f32::NAN))
+        );
+        assert_eq!(
+            bits(max_iter_f32(&vec, NanMode::Propagate)),
+            bits(Some(f32::NAN))
+        );
+
+        assert_eq!(
+            bits(min_iter_f32(&vec, NanMode::TotalOrder)),
+            bits(Some(-f32::NAN))
+        );
+        assert_eq!(
+            bits(max_iter_f32(&vec, NanMode::TotalOrder)),
+            bits(Some(f32::NAN))
+        );
+
+        // Signed zeros are ordered, and NaN is only returned if there is nothing else.
+        let zeros = [0.0, -0.0, f32::NAN];
+        assert_eq!(
+            bits(min_iter_f32(&zeros, NanMode::Ignore)),
+            bits(Some(-0.0))
+        );
+        assert_eq!(bits(max_iter_f32(&zeros, NanMode::Ignore)), bits(Some(0.0)));
+        assert_eq!(
+            bits(min_iter_f32(&[-f32::NAN, f32::NAN], NanMode::Ignore)),
+            bits(Some(-f32::NAN))
+        );
+        assert_eq!(min_iter_f32(&[], NanMode::Propagate), None);
+    }
+
+    #[test]
+    fn test_min_max_iter_f64_nan() {
+        let vec = [1.0, f64::NAN, -0.0, f64::INFINITY, 0.0, -f64::NAN, -2.0];
+
+        let bits = |value: Option<f64>| value.map(f64::to_bits);
+
+        assert_eq!(bits(min_iter_f64(&vec)), bits(Some(-2.0)));
+        assert_eq!(bits(max_iter_f64(&vec)), bits(Some(f64::INFINITY)));
+
+        // Signed zeros are ordered, and NaN is only returned if there is nothing else.
+        let zeros = [f64::NAN, 0.0, -0.0];
+        assert_eq!(bits(min_iter_f64(&zeros)), bits(Some(-0.0)));
+        assert_eq!(bits(max_iter_f64(&zeros)), bits(Some(0.0)));
+        assert_eq!(
+            min_max_iter_f64(&zeros).map(|(min, max)| (min.to_bits(), max.to_bits())),
+            Some(((-0.0f64).to_bits(), 0.0f64.to_bits()))
+        );
+        assert_eq!(
+            bits(max_iter_f64(&[-f64::NAN, f64::NAN])),
+            bits(Some(-f64::NAN))
+        );
+        assert_eq!(min_iter_f64(&[]), None);
+    }
+
+    #[test]
+    fn test_arg_min_max_iter() {
+        let vec = [3u32, 1, 4, 1, 5, 9, 2, 6, 5, 9];
+        assert_eq!(argmin_iter_u32(&vec), Some(1));
+        assert_eq!(argmax_iter_u32(&vec), Some(5));
+        assert_eq!(argmin_iter_u32(&[]), None);
+
+        let floats = [f32::NAN, 0.0, -0.0, 2.0, -0.0, 2.0, f32::NAN];
+        assert_eq!(argmin_iter_f32(&floats), Some(2));
+        assert_eq!(argmax_iter_f32(&floats), Some(3));
+        assert_eq!(argmin_iter_f32(&[f32::NAN, f32::NAN]), Some(0));
+        assert_eq!(argmax_iter_f32(&[]), None);
+    }
+
+    #[test]
+    fn test_min_max_iter() {
+        let vec = [3i8, -1, 4, 1, -5, 9, 2];
+        assert_eq!(min_max_iter_i8(&vec), Some((-5, 9)));
+        assert_eq!(min_max_iter_u64(&[7]), Some((7, 7)));
+        assert_eq!(min_max_iter_u8(&[]), None);
+
+        let bits =
+            |value: Option<(f32, f32)>| value.map(|(min, max)| (min.to_bits(), max.to_bits()));
+
+        let floats = [f32::NAN, 0.0, -0.0, 2.0, -f32::NAN];
+        assert_eq!(bits(min_max_iter_f32(&floats)), bits(Some((-0.0, 2.0))));
+        assert_eq!(
+            bits(min_max_iter_f32(&[-f32::NAN, f32::NAN])),
+            bits(Some((-f32::NAN, -f32::NAN)))
+        );
+        assert_eq!(min_max_iter_f64(&[1.5, -2.0, 0.5]), Some((-2.0, 1.5)));
+    }
 }
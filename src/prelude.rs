@@ -1,2 +1,18 @@
+pub use crate::float::{NanMode, SumMode};
 pub use crate::traits::array_f32::ArrayF32SimdExt;
+pub use crate::traits::array_f64::ArrayF64SimdExt;
+pub use crate::traits::array_i8::ArrayI8SimdExt;
+pub use crate::traits::array_i16::ArrayI16SimdExt;
+pub use crate::traits::array_i32::ArrayI32SimdExt;
+pub use crate::traits::array_i64::ArrayI64SimdExt;
+pub use crate::traits::array_u8::ArrayU8SimdExt;
+pub use crate::traits::array_u16::ArrayU16SimdExt;
 pub use crate::traits::array_u32::ArrayU32SimdExt;
+pub use crate::traits::array_u64::ArrayU64SimdExt;
+pub use crate::traits::element::{
+    SimdArgMinMax, SimdElement, SimdFindAll, SimdFindAny, SimdFindSubslice, SimdMismatch,
+    SimdNanMode, SimdRange, SimdReverseSearch, SimdSearch, SimdSum, SimdSumMode, SimdUniform,
+};
+pub use crate::traits::find_all::{FindAll, Position};
+pub use crate::traits::finder::Finder;
+pub use crate::traits::slice::SliceSimdExt;
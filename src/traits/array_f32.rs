@@ -1,50 +1,42 @@
+use crate::float::NanMode;
+use crate::traits::slice::SliceSimdExt;
+
+/// The `f32` operations that were available before [`SliceSimdExt`], which provides all of them and many more.
+///
+/// The methods forward to `SliceSimdExt`, so both traits can be imported at the same time.
 pub trait ArrayF32SimdExt {
-    /// Determines the minimum value inside the array.
+    /// Determines the minimum value inside the array, skipping NaN values as described by [`NanMode::Ignore`].
     fn min_simd(&self) -> Option<f32>;
 
-    /// Determines the maximum value inside the array.
+    /// Determines the maximum value inside the array, skipping NaN values as described by [`NanMode::Ignore`].
     fn max_simd(&self) -> Option<f32>;
+
+    /// Determines the minimum value inside the array, handling NaN values as described by `mode`.
+    fn min_simd_with(&self, mode: NanMode) -> Option<f32>;
+
+    /// Determines the maximum value inside the array, handling NaN values as described by `mode`.
+    fn max_simd_with(&self, mode: NanMode) -> Option<f32>;
 }
 
-#[cfg(target_arch = "x86_64")]
 impl<T: AsRef<[f32]>> ArrayF32SimdExt for T {
     #[inline]
     fn min_simd(&self) -> Option<f32> {
-        use crate::x86_64::AVX2_F32_MIN_SIZE;
-
-        let array = self.as_ref();
-
-        if is_x86_feature_detected!("avx2") && array.len() >= AVX2_F32_MIN_SIZE {
-            unsafe { crate::x86_64::array::f32_impl::min_avx(array) }
-        } else {
-            crate::original::array::min_iter_f32(array)
-        }
+        SliceSimdExt::min_simd(self.as_ref())
     }
 
     #[inline]
     fn max_simd(&self) -> Option<f32> {
-        use crate::x86_64::AVX2_F32_MIN_SIZE;
-
-        let array = self.as_ref();
-
-        if is_x86_feature_detected!("avx2") && array.len() >= AVX2_F32_MIN_SIZE {
-            unsafe { crate::x86_64::array::f32_impl::max_avx(array) }
-        } else {
-            crate::original::array::max_iter_f32(array)
-        }
+        SliceSimdExt::max_simd(self.as_ref())
     }
-}
 
-#[cfg(not(target_arch = "x86_64"))]
-impl<T: AsRef<[f32]>> ArrayF32SimdExt for T {
     #[inline]
-    fn min_simd(&self) -> Option<f32> {
-        crate::original::array::min_iter_f32(self.as_ref())
+    fn min_simd_with(&self, mode: NanMode) -> Option<f32> {
+        SliceSimdExt::min_simd_with(self.as_ref(), mode)
     }
 
     #[inline]
-    fn max_simd(&self) -> Option<f32> {
-        crate::original::array::max_iter_f32(self.as_ref())
+    fn max_simd_with(&self, mode: NanMode) -> Option<f32> {
+        SliceSimdExt::max_simd_with(self.as_ref(), mode)
     }
 }
 
@@ -54,6 +46,7 @@ mod test {
     use rand::{RngCore, SeedableRng};
 
     use super::*;
+    use crate::float::NanMode;
     use crate::original::array::*;
 
     #[test]
@@ -62,7 +55,32 @@ mod test {
 
         let vec: Vec<_> = (0..100).map(|_| rng.next_u32() as f32).collect();
 
-        assert_eq!(vec.max_simd(), max_iter_f32(&vec));
-        assert_eq!(vec.min_simd(), min_iter_f32(&vec));
+        assert_eq!(vec.max_simd(), max_iter_f32(&vec, NanMode::Ignore));
+        assert_eq!(vec.min_simd(), min_iter_f32(&vec, NanMode::Ignore));
+    }
+
+    #[test]
+    fn test_array_f32_nan() {
+        let mut vec: Vec<_> = (0..100).map(|i| i as f32).collect();
+        vec[50] = f32::NAN;
+
+        assert_eq!(vec.min_simd(), Some(0.0));
+        assert_eq!(vec.max_simd(), Some(99.0));
+        assert!(vec.min_simd_with(NanMode::Propagate).unwrap().is_nan());
+        assert!(vec.max_simd_with(NanMode::Propagate).unwrap().is_nan());
+        assert_eq!(vec.min_simd_with(NanMode::TotalOrder), Some(0.0));
+        assert!(vec.max_simd_with(NanMode::TotalOrder).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_array_f32_arg_min_max() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let vec: Vec<f32> = (0..300).map(|_| (rng.next_u32() % 1000) as f32).collect();
+
+        assert_eq!(vec.argmin_simd(), argmin_iter_f32(&vec));
+        assert_eq!(vec.argmax_simd(), argmax_iter_f32(&vec));
+        assert_eq!(vec[vec.argmin_simd().unwrap()], vec.min_simd().unwrap());
+        assert_eq!(vec[vec.argmax_simd().unwrap()], vec.max_simd().unwrap());
     }
 }
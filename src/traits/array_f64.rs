@@ -0,0 +1,102 @@
+use crate::traits::slice::SliceSimdExt;
+
+/// The `f64` operations that were available before [`SliceSimdExt`], which provides all of them and many more.
+///
+/// The methods forward to `SliceSimdExt`, so both traits can be imported at the same time.
+pub trait ArrayF64SimdExt {
+    /// Determines the minimum value inside the array.
+    fn min_simd(&self) -> Option<f64>;
+
+    /// Determines the maximum value inside the array.
+    fn max_simd(&self) -> Option<f64>;
+
+    /// Returns the index of the first element that is equal to `needle`.
+    /// `NaN` is never equal to anything, so searching for it always returns `None`.
+    fn find_simd(&self, needle: f64) -> Option<usize>;
+
+    /// Returns `true` if the array contains an element equal to `needle`.
+    fn contains_simd(&self, needle: f64) -> bool;
+
+    /// Counts the elements that are equal to `needle`.
+    fn count_simd(&self, needle: f64) -> usize;
+
+    /// Calculates the sum of all elements, or `0.0` if the array is empty.
+    ///
+    /// The elements are added in a different order than by [`Iterator::sum`], so the result can differ from it by
+    /// rounding.
+    fn sum_simd(&self) -> f64;
+}
+
+impl<T: AsRef<[f64]>> ArrayF64SimdExt for T {
+    #[inline]
+    fn min_simd(&self) -> Option<f64> {
+        SliceSimdExt::min_simd(self.as_ref())
+    }
+
+    #[inline]
+    fn max_simd(&self) -> Option<f64> {
+        SliceSimdExt::max_simd(self.as_ref())
+    }
+
+    #[inline]
+    fn find_simd(&self, needle: f64) -> Option<usize> {
+        SliceSimdExt::find_simd(self.as_ref(), needle)
+    }
+
+    #[inline]
+    fn contains_simd(&self, needle: f64) -> bool {
+        SliceSimdExt::contains_simd(self.as_ref(), needle)
+    }
+
+    #[inline]
+    fn count_simd(&self, needle: f64) -> usize {
+        SliceSimdExt::count_simd(self.as_ref(), needle)
+    }
+
+    #[inline]
+    fn sum_simd(&self) -> f64 {
+        SliceSimdExt::sum_simd(self.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+    use crate::original::array::*;
+
+    #[test]
+    fn test_array_f64() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let vec: Vec<_> = (0..200).map(|_| (rng.next_u32() % 100) as f64).collect();
+
+        assert_eq!(vec.max_simd(), max_iter_f64(&vec));
+        assert_eq!(vec.min_simd(), min_iter_f64(&vec));
+
+        assert_eq!(vec.count_simd(vec[10]), count_iter_f64(&vec, vec[10]));
+        assert_eq!(vec.find_simd(vec[199]), find_iter_f64(&vec, vec[199]));
+        assert!(vec.contains_simd(vec[199]));
+        assert!(!vec.contains_simd(0.5));
+
+        assert_eq!(vec.sum_simd(), sum_iter_f64(&vec));
+    }
+
+    #[test]
+    fn test_array_f64_nan() {
+        // The result doesn't depend on whether the length is below the threshold.
+        for len in [3, 31, 40, 1000] {
+            let mut vec = vec![5.0; len];
+            vec[0] = f64::NAN;
+            vec[len - 1] = -0.0;
+
+            assert_eq!(vec.min_simd().map(f64::to_bits), Some((-0.0f64).to_bits()));
+            assert_eq!(vec.max_simd(), Some(5.0));
+            assert_eq!(vec.min_max_simd().map(|(_, max)| max), Some(5.0));
+        }
+
+        assert!([f64::NAN; 40].min_simd().unwrap().is_nan());
+    }
+}
@@ -0,0 +1,73 @@
+use crate::traits::slice::SliceSimdExt;
+
+/// The `u16` operations that were available before [`SliceSimdExt`], which provides all of them and many more.
+///
+/// The methods forward to `SliceSimdExt`, so both traits can be imported at the same time.
+pub trait ArrayU16SimdExt {
+    /// Determines the minimum value inside the array.
+    fn min_simd(&self) -> Option<u16>;
+
+    /// Determines the maximum value inside the array.
+    fn max_simd(&self) -> Option<u16>;
+
+    /// Finds the given `needle` and returns its first occurrence's position or `None` if `needle` is not an element in the array.
+    fn find_simd(&self, needle: u16) -> Option<usize>;
+
+    /// Returns `true` if `needle` is an element in the array.
+    fn contains_simd(&self, needle: u16) -> bool;
+
+    /// Counts the occurrences of `element` in the array.
+    fn count_simd(&self, element: u16) -> usize;
+}
+
+impl<T: AsRef<[u16]>> ArrayU16SimdExt for T {
+    #[inline]
+    fn min_simd(&self) -> Option<u16> {
+        SliceSimdExt::min_simd(self.as_ref())
+    }
+
+    #[inline]
+    fn max_simd(&self) -> Option<u16> {
+        SliceSimdExt::max_simd(self.as_ref())
+    }
+
+    #[inline]
+    fn find_simd(&self, needle: u16) -> Option<usize> {
+        SliceSimdExt::find_simd(self.as_ref(), needle)
+    }
+
+    #[inline]
+    fn contains_simd(&self, needle: u16) -> bool {
+        SliceSimdExt::contains_simd(self.as_ref(), needle)
+    }
+
+    #[inline]
+    fn count_simd(&self, element: u16) -> usize {
+        SliceSimdExt::count_simd(self.as_ref(), element)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+    use crate::original::array::*;
+
+    #[test]
+    fn test_array_u16() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let vec: Vec<u16> = (0..200).map(|_| rng.next_u64() as u16).collect();
+
+        assert_eq!(vec.count_simd(vec[10]), count_iter_u16(&vec, vec[10]));
+        assert_eq!(vec.count_simd(42), count_iter_u16(&vec, 42));
+
+        assert_eq!(vec.max_simd(), max_iter_u16(&vec));
+        assert_eq!(vec.min_simd(), min_iter_u16(&vec));
+
+        assert_eq!(vec.find_simd(vec[199]), find_iter_u16(&vec, vec[199]));
+        assert!(vec.contains_simd(vec[199]));
+    }
+}
@@ -1,3 +1,8 @@
+use crate::traits::slice::SliceSimdExt;
+
+/// The `u32` operations that were available before [`SliceSimdExt`], which provides all of them and many more.
+///
+/// The methods forward to `SliceSimdExt`, so both traits can be imported at the same time.
 pub trait ArrayU32SimdExt {
     /// Determines the minimum value inside the array.
     fn min_simd(&self) -> Option<u32>;
@@ -15,94 +20,30 @@ pub trait ArrayU32SimdExt {
     fn count_simd(&self, element: u32) -> usize;
 }
 
-#[cfg(target_arch = "x86_64")]
-impl<T: AsRef<[u32]>> ArrayU32SimdExt for T {
-    #[inline]
-    fn min_simd(&self) -> Option<u32> {
-        use crate::x86_64::AVX2_U32_MIN_SIZE;
-        let array = self.as_ref();
-
-        if is_x86_feature_detected!("avx2") && array.len() >= AVX2_U32_MIN_SIZE {
-            unsafe { crate::x86_64::array::u32_impl::min_avx(array) }
-        } else {
-            crate::original::array::min_iter_u32(array)
-        }
-    }
-
-    #[inline]
-    fn max_simd(&self) -> Option<u32> {
-        use crate::x86_64::AVX2_U32_MIN_SIZE;
-        let array = self.as_ref();
-
-        if is_x86_feature_detected!("avx2") && array.len() >= AVX2_U32_MIN_SIZE {
-            unsafe { crate::x86_64::array::u32_impl::max_avx(array) }
-        } else {
-            crate::original::array::max_iter_u32(array)
-        }
-    }
-
-    #[inline]
-    fn find_simd(&self, needle: u32) -> Option<usize> {
-        use crate::x86_64::AVX2_U32_MIN_SIZE;
-
-        let array = self.as_ref();
-
-        if is_x86_feature_detected!("avx2") && array.len() >= AVX2_U32_MIN_SIZE {
-            unsafe { crate::x86_64::array::u32_impl::find_avx(array, needle) }
-        } else {
-            crate::original::array::find_iter_u32(array, needle)
-        }
-    }
-
-    fn contains_simd(&self, needle: u32) -> bool {
-        use crate::x86_64::AVX2_U32_MIN_SIZE;
-        let array = self.as_ref();
-
-        if is_x86_feature_detected!("avx2") && array.len() >= AVX2_U32_MIN_SIZE {
-            unsafe { crate::x86_64::array::u32_impl::contains_avx(array, needle) }
-        } else {
-            crate::original::array::contains_iter_u32(array, needle)
-        }
-    }
-
-    #[inline]
-    fn count_simd(&self, element: u32) -> usize {
-        use crate::x86_64::AVX2_U32_MIN_SIZE;
-        let array = self.as_ref();
-
-        if is_x86_feature_detected!("avx2") && array.len() >= AVX2_U32_MIN_SIZE {
-            unsafe { crate::x86_64::array::u32_impl::count_avx(array, element) }
-        } else {
-            crate::original::array::count_iter_u32(array, element)
-        }
-    }
-}
-
-#[cfg(not(target_arch = "x86_64"))]
 impl<T: AsRef<[u32]>> ArrayU32SimdExt for T {
     #[inline]
     fn min_simd(&self) -> Option<u32> {
-        crate::original::array::min_iter_u32(self.as_ref())
+        SliceSimdExt::min_simd(self.as_ref())
     }
 
     #[inline]
     fn max_simd(&self) -> Option<u32> {
-        crate::original::array::max_iter_u32(self.as_ref())
+        SliceSimdExt::max_simd(self.as_ref())
     }
 
     #[inline]
     fn find_simd(&self, needle: u32) -> Option<usize> {
-        crate::original::array::find_iter_u32(self.as_ref(), needle)
+        SliceSimdExt::find_simd(self.as_ref(), needle)
     }
 
     #[inline]
     fn contains_simd(&self, needle: u32) -> bool {
-        crate::original::array::contains_iter_u32(self.as_ref(), needle)
+        SliceSimdExt::contains_simd(self.as_ref(), needle)
     }
 
     #[inline]
     fn count_simd(&self, element: u32) -> usize {
-        crate::original::array::count_iter_u32(self.as_ref(), element)
+        SliceSimdExt::count_simd(self.as_ref(), element)
     }
 }
 
@@ -128,4 +69,28 @@ mod test {
 
         assert_eq!(vec.find_simd(42), find_iter_u32(&vec, 42));
     }
+
+    #[test]
+    fn test_array_u32_bound() {
+        fn largest<A: ArrayU32SimdExt>(array: &A) -> Option<u32> {
+            array.max_simd()
+        }
+
+        let vec = vec![3u32, 9, 1];
+        assert_eq!(largest(&vec), Some(9));
+        assert_eq!(largest(&&vec[..1]), Some(3));
+        assert_eq!(largest(&[7u32; 40]), Some(7));
+    }
+
+    #[test]
+    fn test_array_u32_arg_min_max() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let vec: Vec<u32> = (0..300).map(|_| rng.next_u32()).collect();
+
+        assert_eq!(vec.argmin_simd(), argmin_iter_u32(&vec));
+        assert_eq!(vec.argmax_simd(), argmax_iter_u32(&vec));
+        assert_eq!(vec[vec.argmin_simd().unwrap()], vec.min_simd().unwrap());
+        assert_eq!(vec[vec.argmax_simd().unwrap()], vec.max_simd().unwrap());
+    }
 }
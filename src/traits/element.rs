@@ -0,0 +1,408 @@
+use crate::dispatch::kernels;
+use crate::float::{NanMode, SumMode};
+use crate::traits::finder::Finder;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive type the operations of [`SliceSimdExt`](crate::traits::slice::SliceSimdExt) are provided for.
+///
+/// This trait is sealed and implemented for all integer types up to 64 bit, `f32` and `f64`. Its functions are
+/// used by [`SliceSimdExt`](crate::traits::slice::SliceSimdExt) and not meant to be called directly.
+pub trait SimdElement: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    fn slice_min(array: &[Self]) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn slice_max(array: &[Self]) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn slice_min_max(array: &[Self]) -> Option<(Self, Self)>;
+}
+
+/// Element types that can be searched by equality, which are all of them except `f32`.
+pub trait SimdSearch: SimdElement {
+    #[doc(hidden)]
+    fn slice_find(array: &[Self], needle: Self) -> Option<usize>;
+
+    #[doc(hidden)]
+    fn slice_contains(array: &[Self], needle: Self) -> bool;
+
+    #[doc(hidden)]
+    fn slice_count(array: &[Self], element: Self) -> usize;
+}
+
+/// Element types that can be searched from the end, which currently are `u8` and `u32`.
+pub trait SimdReverseSearch: SimdSearch {
+    #[doc(hidden)]
+    fn slice_rfind(array: &[Self], needle: Self) -> Option<usize>;
+}
+
+/// Element types whose occurrences can all be found in blocks, which currently are `u8` and `u32`.
+pub trait SimdFindAll: SimdSearch {
+    /// Returns the start of the first block of 64 items containing `needle` and the mask of its occurrences in it.
+    #[doc(hidden)]
+    fn slice_find_all(array: &[Self], needle: Self) -> Option<(usize, u64)>;
+}
+
+/// Element types that can be searched for several needles at once, which currently is only `u8`.
+pub trait SimdFindAny: SimdSearch {
+    #[doc(hidden)]
+    fn slice_find_any2(array: &[Self], a: Self, b: Self) -> Option<usize>;
+
+    #[doc(hidden)]
+    fn slice_find_any3(array: &[Self], a: Self, b: Self, c: Self) -> Option<usize>;
+
+    #[doc(hidden)]
+    fn slice_find_any_of(array: &[Self], needles: &[Self]) -> Option<usize>;
+}
+
+/// Element types whose slices can be searched for a subslice, which currently is only `u8`.
+pub trait SimdFindSubslice: SimdSearch {
+    #[doc(hidden)]
+    fn slice_find_subslice(haystack: &[Self], needle: &[Self]) -> Option<usize>;
+}
+
+/// Element types whose slices can be checked for consisting of a single value, which currently are `u8` and `u32`.
+pub trait SimdUniform: SimdSearch {
+    #[doc(hidden)]
+    const ZERO: Self;
+
+    #[doc(hidden)]
+    fn slice_all_eq(array: &[Self], value: Self) -> bool;
+}
+
+/// Element types that can be summed up, which currently are `u8`, `u32`, `f32` and `f64`.
+pub trait SimdSum: SimdElement {
+    /// The type of the sum. Integers are summed up as `u64`, so the sum doesn't overflow for realistic lengths.
+    type Sum;
+
+    #[doc(hidden)]
+    fn slice_sum(array: &[Self]) -> Self::Sum;
+}
+
+/// Element types whose sum can be calculated as described by [`SumMode`], which currently is only `f32`.
+pub trait SimdSumMode: SimdSum {
+    #[doc(hidden)]
+    fn slice_sum_with(array: &[Self], mode: SumMode) -> Self::Sum;
+}
+
+/// Element types whose minimum and maximum can handle NaN values as described by [`NanMode`], which currently is
+/// only `f32`.
+pub trait SimdNanMode: SimdElement {
+    #[doc(hidden)]
+    fn slice_min_with(array: &[Self], mode: NanMode) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn slice_max_with(array: &[Self], mode: NanMode) -> Option<Self>;
+}
+
+/// Element types the position of the minimum and maximum can be determined for, which currently are `u8`, `u32` and
+/// `f32`.
+pub trait SimdArgMinMax: SimdElement {
+    #[doc(hidden)]
+    fn slice_argmin(array: &[Self]) -> Option<usize>;
+
+    #[doc(hidden)]
+    fn slice_argmax(array: &[Self]) -> Option<usize>;
+}
+
+/// Element types whose slices can be compared for the first differing item, which currently are `u8`, `u32` and
+/// `f32`.
+pub trait SimdMismatch: SimdElement {
+    #[doc(hidden)]
+    fn slice_mismatch(array: &[Self], other: &[Self]) -> Option<usize>;
+}
+
+/// Element types whose items can be tested for lying within a range, which currently are `u8`, `u32` and `f32`.
+pub trait SimdRange: SimdElement {
+    #[doc(hidden)]
+    fn slice_count_in_range(array: &[Self], lo: Self, hi: Self) -> usize;
+
+    #[doc(hidden)]
+    fn slice_find_in_range(array: &[Self], lo: Self, hi: Self) -> Option<usize>;
+
+    /// The smallest value, which is negative infinity for `f32`.
+    #[doc(hidden)]
+    const LOWEST: Self;
+
+    /// The largest value, which is infinity for `f32`.
+    #[doc(hidden)]
+    const HIGHEST: Self;
+
+    /// Returns the smallest value greater than `value`, or `None` if there is none.
+    #[doc(hidden)]
+    fn successor(value: Self) -> Option<Self>;
+
+    /// Returns the largest value less than `value`, or `None` if there is none.
+    #[doc(hidden)]
+    fn predecessor(value: Self) -> Option<Self>;
+}
+
+/// Implements [`SimdArgMinMax`] by calling the kernels of the element type.
+macro_rules! impl_arg_min_max {
+    ($($ty:ident),*) => {
+        $(
+            impl SimdArgMinMax for $ty {
+                #[inline]
+                fn slice_argmin(array: &[$ty]) -> Option<usize> {
+                    (kernels().$ty.argmin)(array)
+                }
+
+                #[inline]
+                fn slice_argmax(array: &[$ty]) -> Option<usize> {
+                    (kernels().$ty.argmax)(array)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`SimdElement`] and [`SimdSearch`] by calling the kernels of the element type.
+macro_rules! impl_search_element {
+    ($($ty:ident),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl SimdElement for $ty {
+                #[inline]
+                fn slice_min(array: &[$ty]) -> Option<$ty> {
+                    (kernels().$ty.min)(array)
+                }
+
+                #[inline]
+                fn slice_max(array: &[$ty]) -> Option<$ty> {
+                    (kernels().$ty.max)(array)
+                }
+
+                #[inline]
+                fn slice_min_max(array: &[$ty]) -> Option<($ty, $ty)> {
+                    (kernels().$ty.min_max)(array)
+                }
+            }
+
+            impl SimdSearch for $ty {
+                #[inline]
+                fn slice_find(array: &[$ty], needle: $ty) -> Option<usize> {
+                    (kernels().$ty.find)(array, needle)
+                }
+
+                #[inline]
+                fn slice_contains(array: &[$ty], needle: $ty) -> bool {
+                    (kernels().$ty.contains)(array, needle)
+                }
+
+                #[inline]
+                fn slice_count(array: &[$ty], element: $ty) -> usize {
+                    (kernels().$ty.count)(array, element)
+                }
+            }
+        )*
+    };
+}
+
+impl_search_element!(u8, u16, u32, u64, i8, i16, i32, i64, f64);
+
+/// Implements [`SimdMismatch`] by calling the kernels of the element type.
+macro_rules! impl_mismatch {
+    ($($ty:ident),*) => {
+        $(
+            impl SimdMismatch for $ty {
+                #[inline]
+                fn slice_mismatch(array: &[$ty], other: &[$ty]) -> Option<usize> {
+                    (kernels().$ty.mismatch)(array, other)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`SimdRange`] by calling the kernels of the element type.
+macro_rules! impl_range {
+    ($($ty:ident: $lowest:expr, $highest:expr, $successor:expr, $predecessor:expr);*) => {
+        $(
+            impl SimdRange for $ty {
+                const LOWEST: $ty = $lowest;
+                const HIGHEST: $ty = $highest;
+
+                #[inline]
+                fn successor(value: $ty) -> Option<$ty> {
+                    ($successor)(value)
+                }
+
+                #[inline]
+                fn predecessor(value: $ty) -> Option<$ty> {
+                    ($predecessor)(value)
+                }
+
+                #[inline]
+                fn slice_count_in_range(array: &[$ty], lo: $ty, hi: $ty) -> usize {
+                    (kernels().$ty.count_in_range)(array, lo, hi)
+                }
+
+                #[inline]
+                fn slice_find_in_range(array: &[$ty], lo: $ty, hi: $ty) -> Option<usize> {
+                    (kernels().$ty.find_in_range)(array, lo, hi)
+                }
+            }
+        )*
+    };
+}
+
+impl_arg_min_max!(u8, u32, f32);
+
+impl_mismatch!(u8, u32, f32);
+
+impl_range!(
+    u8: u8::MIN, u8::MAX, |value: u8| value.checked_add(1), |value: u8| value.checked_sub(1);
+    u32: u32::MIN, u32::MAX, |value: u32| value.checked_add(1), |value: u32| value.checked_sub(1);
+    // Both comparisons are false for NaN, so no value is ordered next to it.
+    f32: f32::NEG_INFINITY, f32::INFINITY,
+        |value: f32| (value < f32::INFINITY).then(|| value.next_up()),
+        |value: f32| (value > f32::NEG_INFINITY).then(|| value.next_down())
+);
+
+impl SimdReverseSearch for u8 {
+    #[inline]
+    fn slice_rfind(array: &[u8], needle: u8) -> Option<usize> {
+        (kernels().u8.rfind)(array, needle)
+    }
+}
+
+impl SimdReverseSearch for u32 {
+    #[inline]
+    fn slice_rfind(array: &[u32], needle: u32) -> Option<usize> {
+        (kernels().u32.rfind)(array, needle)
+    }
+}
+
+impl SimdFindAny for u8 {
+    #[inline]
+    fn slice_find_any2(array: &[u8], a: u8, b: u8) -> Option<usize> {
+        (kernels().u8.find_any2)(array, a, b)
+    }
+
+    #[inline]
+    fn slice_find_any3(array: &[u8], a: u8, b: u8, c: u8) -> Option<usize> {
+        (kernels().u8.find_any3)(array, a, b, c)
+    }
+
+    #[inline]
+    fn slice_find_any_of(array: &[u8], needles: &[u8]) -> Option<usize> {
+        (kernels().u8.find_any_of)(array, needles)
+    }
+}
+
+impl SimdFindSubslice for u8 {
+    #[inline]
+    fn slice_find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        Finder::new(needle).find(haystack)
+    }
+}
+
+impl SimdUniform for u8 {
+    const ZERO: u8 = 0;
+
+    #[inline]
+    fn slice_all_eq(array: &[u8], value: u8) -> bool {
+        (kernels().u8.all_eq)(array, value)
+    }
+}
+
+impl SimdUniform for u32 {
+    const ZERO: u32 = 0;
+
+    #[inline]
+    fn slice_all_eq(array: &[u32], value: u32) -> bool {
+        (kernels().u32.all_eq)(array, value)
+    }
+}
+
+impl SimdFindAll for u8 {
+    #[inline]
+    fn slice_find_all(array: &[u8], needle: u8) -> Option<(usize, u64)> {
+        (kernels().u8.find_all)(array, needle)
+    }
+}
+
+impl SimdFindAll for u32 {
+    #[inline]
+    fn slice_find_all(array: &[u32], needle: u32) -> Option<(usize, u64)> {
+        (kernels().u32.find_all)(array, needle)
+    }
+}
+
+impl SimdSum for u8 {
+    type Sum = u64;
+
+    #[inline]
+    fn slice_sum(array: &[u8]) -> u64 {
+        (kernels().u8.sum)(array)
+    }
+}
+
+impl SimdSum for u32 {
+    type Sum = u64;
+
+    #[inline]
+    fn slice_sum(array: &[u32]) -> u64 {
+        (kernels().u32.sum)(array)
+    }
+}
+
+impl SimdSum for f64 {
+    type Sum = f64;
+
+    #[inline]
+    fn slice_sum(array: &[f64]) -> f64 {
+        (kernels().f64.sum)(array)
+    }
+}
+
+impl sealed::Sealed for f32 {}
+
+impl SimdElement for f32 {
+    #[inline]
+    fn slice_min(array: &[f32]) -> Option<f32> {
+        (kernels().f32.min)(array, NanMode::Ignore)
+    }
+
+    #[inline]
+    fn slice_max(array: &[f32]) -> Option<f32> {
+        (kernels().f32.max)(array, NanMode::Ignore)
+    }
+
+    #[inline]
+    fn slice_min_max(array: &[f32]) -> Option<(f32, f32)> {
+        (kernels().f32.min_max)(array)
+    }
+}
+
+impl SimdNanMode for f32 {
+    #[inline]
+    fn slice_min_with(array: &[f32], mode: NanMode) -> Option<f32> {
+        (kernels().f32.min)(array, mode)
+    }
+
+    #[inline]
+    fn slice_max_with(array: &[f32], mode: NanMode) -> Option<f32> {
+        (kernels().f32.max)(array, mode)
+    }
+}
+
+impl SimdSum for f32 {
+    type Sum = f32;
+
+    #[inline]
+    fn slice_sum(array: &[f32]) -> f32 {
+        (kernels().f32.sum)(array, SumMode::Fast)
+    }
+}
+
+impl SimdSumMode for f32 {
+    #[inline]
+    fn slice_sum_with(array: &[f32], mode: SumMode) -> f32 {
+        (kernels().f32.sum)(array, mode)
+    }
+}
@@ -0,0 +1,151 @@
+use std::iter::FusedIterator;
+
+use crate::traits::element::SimdFindAll;
+
+/// The number of items covered by the mask of a block.
+const BLOCK_LEN: usize = 64;
+
+/// Iterator over the positions of all occurrences of a needle, in ascending order.
+///
+/// Created by [`find_all_simd`](crate::traits::slice::SliceSimdExt::find_all_simd). The array is searched lazily, one
+/// block of 64 items at a time, so stopping early skips the rest of the array.
+#[derive(Clone, Debug)]
+pub struct FindAll<'a, T> {
+    array: &'a [T],
+    needle: T,
+    /// The position the bits of `mask` are relative to.
+    block_start: usize,
+    /// The occurrences in the current block that haven't been returned yet.
+    mask: u64,
+    /// The position the search continues at once `mask` is empty.
+    searched: usize,
+}
+
+impl<'a, T: SimdFindAll> FindAll<'a, T> {
+    pub(crate) fn new(array: &'a [T], needle: T) -> Self {
+        Self {
+            array,
+            needle,
+            block_start: 0,
+            mask: 0,
+            searched: 0,
+        }
+    }
+}
+
+impl<T: SimdFindAll> Iterator for FindAll<'_, T> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.mask == 0 {
+            let rest = &self.array[self.searched..];
+
+            let Some((start, mask)) = T::slice_find_all(rest, self.needle) else {
+                self.searched = self.array.len();
+                return None;
+            };
+
+            self.block_start = self.searched + start;
+            self.mask = mask;
+            self.searched = (self.block_start + BLOCK_LEN).min(self.array.len());
+        }
+
+        let position = self.block_start + self.mask.trailing_zeros() as usize;
+        self.mask &= self.mask - 1;
+
+        Some(position)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.mask.count_ones() as usize;
+        (pending, Some(pending + self.array.len() - self.searched))
+    }
+}
+
+impl<T: SimdFindAll> FusedIterator for FindAll<'_, T> {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Integer types positions can be written as by
+/// [`find_all_simd_into`](crate::traits::slice::SliceSimdExt::find_all_simd_into), which are `usize` and `u32`.
+pub trait Position: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    fn from_position(position: usize) -> Self;
+}
+
+impl sealed::Sealed for usize {}
+
+impl Position for usize {
+    #[inline]
+    fn from_position(position: usize) -> usize {
+        position
+    }
+}
+
+impl sealed::Sealed for u32 {}
+
+impl Position for u32 {
+    #[inline]
+    fn from_position(position: usize) -> u32 {
+        u32::try_from(position).expect("position doesn't fit into u32")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use crate::traits::slice::SliceSimdExt;
+
+    #[test]
+    fn test_find_all_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 31, 63, 64, 65, 127, 128, 129, 191, 300, 1000] {
+            for rate in [1, 2, 7, 64, 500] {
+                let u8: Vec<u8> = (0..len).map(|_| (rng.next_u32() % rate) as u8).collect();
+                let u32: Vec<u32> = u8.iter().map(|i| *i as u32 * 1000).collect();
+
+                let expected: Vec<usize> = (0..len).filter(|i| u8[*i] == 0).collect();
+
+                assert_eq!(u8.find_all_simd(0).collect::<Vec<_>>(), expected);
+                assert_eq!(u32.find_all_simd(0).collect::<Vec<_>>(), expected);
+
+                let mut positions = vec![usize::MAX];
+                u8.find_all_simd_into(0, &mut positions);
+                assert_eq!(positions[0], usize::MAX);
+                assert_eq!(positions[1..], expected);
+
+                let mut positions: Vec<u32> = Vec::new();
+                u32.find_all_simd_into(0, &mut positions);
+                assert!(
+                    positions
+                        .iter()
+                        .map(|i| *i as usize)
+                        .eq(expected.iter().copied())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_all_lazy() {
+        let mut array = vec![0u8; 1000];
+        array[3] = 1;
+        array[500] = 1;
+        array[999] = 1;
+
+        let mut iter = array.find_all_simd(1);
+        assert_eq!(iter.size_hint(), (0, Some(1000)));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(500));
+        assert_eq!(iter.next(), Some(999));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+
+use crate::dispatch::kernels;
+use crate::traits::slice::SliceSimdExt;
+
+/// A byte string prepared to be searched for in many haystacks, as done by
+/// [`find_subslice_simd`](SliceSimdExt::find_subslice_simd) for a single one.
+///
+/// Besides the first byte, the search compares a second byte of the needle at every position before comparing the
+/// whole needle. That byte is chosen once when the finder is created.
+#[derive(Clone, Debug)]
+pub struct Finder<'n> {
+    needle: Cow<'n, [u8]>,
+    /// The offset of the second byte that is compared.
+    probe: usize,
+}
+
+impl<'n> Finder<'n> {
+    /// Prepares the search for `needle`.
+    pub fn new(needle: &'n [u8]) -> Self {
+        // The last byte rules out the most candidates unless it equals the first one, as in `"abca"`.
+        let probe = needle
+            .iter()
+            .rposition(|byte| *byte != needle[0])
+            .unwrap_or(needle.len().saturating_sub(1));
+
+        Self {
+            needle: Cow::Borrowed(needle),
+            probe,
+        }
+    }
+
+    /// Returns the needle that is searched for.
+    pub fn needle(&self) -> &[u8] {
+        &self.needle
+    }
+
+    /// Converts the finder into one that owns its needle, so it can be kept independently of it.
+    pub fn into_owned(self) -> Finder<'static> {
+        Finder {
+            needle: Cow::Owned(self.needle.into_owned()),
+            probe: self.probe,
+        }
+    }
+
+    /// Returns the position of the first occurrence of the needle in `haystack`, or `None` if it doesn't contain it.
+    /// An empty needle is found at position `0`.
+    #[inline]
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        match *self.needle {
+            [] => Some(0),
+            [byte] => haystack.find_simd(byte),
+            _ => (kernels().u8.find_subslice)(haystack, &self.needle, self.probe),
+        }
+    }
+
+    /// Returns `true` if `haystack` contains the needle.
+    #[inline]
+    pub fn contains(&self, haystack: &[u8]) -> bool {
+        self.find(haystack).is_some()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_finder() {
+        let haystack = b"2024-01-01 INFO started\n2024-01-01 ERROR disk full\n";
+
+        let finder = Finder::new(b"ERROR");
+        assert_eq!(finder.find(haystack), Some(35));
+        assert!(finder.contains(haystack));
+        assert!(!finder.contains(&haystack[..38]));
+
+        let owned = {
+            let needle = b"full\n".to_vec();
+            Finder::new(&needle).into_owned()
+        };
+        assert_eq!(owned.needle(), b"full\n");
+        assert_eq!(owned.find(haystack), Some(46));
+
+        assert_eq!(Finder::new(b"").find(haystack), Some(0));
+        assert_eq!(Finder::new(b"").find(b""), Some(0));
+        assert_eq!(Finder::new(b"E").find(haystack), Some(35));
+        assert_eq!(Finder::new(b"WARN").find(haystack), None);
+        assert_eq!(Finder::new(haystack).find(&haystack[1..]), None);
+    }
+
+    #[test]
+    fn test_finder_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 2, 31, 32, 33, 64, 100, 300, 1000] {
+            // A small alphabet makes partial matches common.
+            let haystack: Vec<u8> = (0..len)
+                .map(|_| b"aab"[rng.next_u32() as usize % 3])
+                .collect();
+
+            for needle_len in [1, 2, 3, 5, 8, 16, 40] {
+                let needle: Vec<u8> = if len >= needle_len && rng.next_u32() % 2 == 0 {
+                    let start = rng.next_u32() as usize % (len - needle_len + 1);
+                    haystack[start..start + needle_len].to_vec()
+                } else {
+                    (0..needle_len)
+                        .map(|_| b"aab"[rng.next_u32() as usize % 3])
+                        .collect()
+                };
+
+                let expected = haystack
+                    .windows(needle_len)
+                    .position(|window| window == needle);
+                assert_eq!(Finder::new(&needle).find(&haystack), expected, "{needle:?}");
+            }
+        }
+    }
+}
@@ -1,3 +1,14 @@
 pub mod array_f32;
+pub mod array_f64;
+pub mod array_i16;
+pub mod array_i32;
+pub mod array_i64;
+pub mod array_i8;
+pub mod array_u16;
 pub mod array_u32;
+pub mod array_u64;
 pub mod array_u8;
+pub mod element;
+pub mod find_all;
+pub mod finder;
+pub mod slice;
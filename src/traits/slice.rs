@@ -0,0 +1,630 @@
+use std::cmp::Ordering;
+
+use crate::float::{NanMode, SumMode};
+use crate::traits::element::{
+    SimdArgMinMax, SimdElement, SimdFindAll, SimdFindAny, SimdFindSubslice, SimdMismatch,
+    SimdNanMode, SimdRange, SimdReverseSearch, SimdSearch, SimdSum, SimdSumMode, SimdUniform,
+};
+use crate::traits::find_all::{FindAll, Position};
+
+/// Vectorized operations on slices of any [`SimdElement`]. Vectors and arrays get them through auto-deref, so
+/// `vec.min_simd()` works as well.
+///
+/// Generic code can use these operations by requiring `T: SimdElement`. Operations that aren't provided for every
+/// element type require an additional bound, e.g. [`SimdSearch`] for [`find_simd`](SliceSimdExt::find_simd).
+pub trait SliceSimdExt<T: SimdElement> {
+    /// Determines the minimum value inside the array.
+    ///
+    /// NaN values of `f32` and `f64` are skipped as described by [`NanMode::Ignore`].
+    fn min_simd(&self) -> Option<T>;
+
+    /// Determines the maximum value inside the array.
+    ///
+    /// NaN values of `f32` and `f64` are skipped as described by [`NanMode::Ignore`].
+    fn max_simd(&self) -> Option<T>;
+
+    /// Determines the minimum and the maximum value inside the array in a single pass, which is faster than calling
+    /// [`min_simd`](SliceSimdExt::min_simd) and [`max_simd`](SliceSimdExt::max_simd) for arrays that don't fit
+    /// into the cache.
+    ///
+    /// NaN values of `f32` and `f64` are skipped as described by [`NanMode::Ignore`].
+    fn min_max_simd(&self) -> Option<(T, T)>;
+
+    /// Finds the given `needle` and returns its first occurrence's position or `None` if `needle` is not an element in the array.
+    fn find_simd(&self, needle: T) -> Option<usize>
+    where
+        T: SimdSearch;
+
+    /// Finds the given `needle` and returns its last occurrence's position or `None` if `needle` is not an element in
+    /// the array.
+    fn rfind_simd(&self, needle: T) -> Option<usize>
+    where
+        T: SimdReverseSearch;
+
+    /// Returns the position of the first item that is `a` or `b`, or `None` if neither is an element in the array.
+    fn find_any2_simd(&self, a: T, b: T) -> Option<usize>
+    where
+        T: SimdFindAny;
+
+    /// Returns the position of the first item that is `a`, `b` or `c`, or `None` if none of them is an element in the
+    /// array.
+    fn find_any3_simd(&self, a: T, b: T, c: T) -> Option<usize>
+    where
+        T: SimdFindAny;
+
+    /// Returns the position of the first item contained in `needles`, or `None` if there is none.
+    ///
+    /// The needles are looked up in a table, so their number barely affects the speed of the search.
+    fn find_any_of_simd(&self, needles: &[T]) -> Option<usize>
+    where
+        T: SimdFindAny;
+
+    /// Returns the position of the first occurrence of `needle` as a subslice, or `None` if the array doesn't contain
+    /// it. An empty `needle` is found at position `0`.
+    ///
+    /// Use a [`Finder`](crate::traits::finder::Finder) to search for the same needle repeatedly.
+    fn find_subslice_simd(&self, needle: &[T]) -> Option<usize>
+    where
+        T: SimdFindSubslice;
+
+    /// Returns `true` if the array contains `needle` as a subslice.
+    fn contains_subslice_simd(&self, needle: &[T]) -> bool
+    where
+        T: SimdFindSubslice;
+
+    /// Returns an iterator over the positions of all occurrences of `needle`, in ascending order.
+    ///
+    /// The array is searched lazily while iterating, which is fast for rare needles as well as for frequent ones.
+    fn find_all_simd(&self, needle: T) -> FindAll<'_, T>
+    where
+        T: SimdFindAll;
+
+    /// Appends the positions of all occurrences of `needle` to `positions`, in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a position doesn't fit into `P`, i.e. if it exceeds `u32::MAX` for `Vec<u32>`.
+    fn find_all_simd_into<P: Position>(&self, needle: T, positions: &mut Vec<P>)
+    where
+        T: SimdFindAll;
+
+    /// Returns `true` if `needle` is an element in the array.
+    fn contains_simd(&self, needle: T) -> bool
+    where
+        T: SimdSearch;
+
+    /// Counts the occurrences of `element` in the array.
+    fn count_simd(&self, element: T) -> usize
+    where
+        T: SimdSearch;
+
+    /// Returns `true` if all items of the array are `value`, which is the case for an empty array.
+    fn all_eq_simd(&self, value: T) -> bool
+    where
+        T: SimdUniform;
+
+    /// Returns `true` if all items of the array are zero, which is the case for an empty array.
+    fn is_zero_simd(&self) -> bool
+    where
+        T: SimdUniform;
+
+    /// Counts the items within `lo..=hi`. Integers are compared unsigned and NaN values of `f32` are never within
+    /// the range.
+    fn count_in_range_simd(&self, lo: T, hi: T) -> usize
+    where
+        T: SimdRange;
+
+    /// Returns the position of the first item within `lo..=hi`, or `None` if there is none.
+    fn find_first_in_range_simd(&self, lo: T, hi: T) -> Option<usize>
+    where
+        T: SimdRange;
+
+    /// Returns `true` if any item lies within `lo..=hi`.
+    fn any_in_range_simd(&self, lo: T, hi: T) -> bool
+    where
+        T: SimdRange;
+
+    /// Returns the position of the first item greater than `value`, or `None` if there is none.
+    ///
+    /// Items are compared like the comparison operators do, so NaN values of `f32` are neither greater nor less than
+    /// any value.
+    fn find_gt_simd(&self, value: T) -> Option<usize>
+    where
+        T: SimdRange;
+
+    /// Returns the position of the first item greater than or equal to `value`, or `None` if there is none.
+    fn find_ge_simd(&self, value: T) -> Option<usize>
+    where
+        T: SimdRange;
+
+    /// Returns the position of the first item less than `value`, or `None` if there is none.
+    fn find_lt_simd(&self, value: T) -> Option<usize>
+    where
+        T: SimdRange;
+
+    /// Returns the position of the first item less than or equal to `value`, or `None` if there is none.
+    fn find_le_simd(&self, value: T) -> Option<usize>
+    where
+        T: SimdRange;
+
+    /// Returns `true` if the array and `other` have the same length and equal items, like `==` does.
+    fn eq_simd(&self, other: &[T]) -> bool
+    where
+        T: SimdMismatch;
+
+    /// Returns the position of the first item that differs from `other`, or `None` if both are equal. If one is a
+    /// prefix of the other, the length of the shorter one is returned.
+    ///
+    /// Items are compared like `==` does, so NaN values of `f32` never match.
+    fn mismatch_simd(&self, other: &[T]) -> Option<usize>
+    where
+        T: SimdMismatch;
+
+    /// Compares the array with `other` lexicographically, like [`Ord::cmp`] of slices does.
+    ///
+    /// Only the first differing item is compared after it has been found with
+    /// [`mismatch_simd`](SliceSimdExt::mismatch_simd). If there is none, the shorter array is ordered first.
+    fn cmp_simd(&self, other: &[T]) -> Ordering
+    where
+        T: SimdMismatch + Ord;
+
+    /// Calculates the sum of all elements, or zero if the array is empty. Integers are summed up as `u64`.
+    ///
+    /// The elements of floats are added in a different order than by [`Iterator::sum`], so the result can differ from
+    /// it by rounding. `f32` elements are added up as described by [`SumMode::Fast`].
+    fn sum_simd(&self) -> T::Sum
+    where
+        T: SimdSum;
+
+    /// Calculates the sum of all elements, or zero if the array is empty, adding them up as described by `mode`.
+    fn sum_simd_with(&self, mode: SumMode) -> T::Sum
+    where
+        T: SimdSumMode;
+
+    /// Returns the position of the first minimum inside the array, or `None` if the array is empty.
+    ///
+    /// NaN values of `f32` are skipped as described by [`NanMode::Ignore`].
+    fn argmin_simd(&self) -> Option<usize>
+    where
+        T: SimdArgMinMax;
+
+    /// Returns the position of the first maximum inside the array, or `None` if the array is empty.
+    ///
+    /// NaN values of `f32` are skipped as described by [`NanMode::Ignore`].
+    fn argmax_simd(&self) -> Option<usize>
+    where
+        T: SimdArgMinMax;
+
+    /// Determines the minimum value inside the array, handling NaN values as described by `mode`.
+    fn min_simd_with(&self, mode: NanMode) -> Option<T>
+    where
+        T: SimdNanMode;
+
+    /// Determines the maximum value inside the array, handling NaN values as described by `mode`.
+    fn max_simd_with(&self, mode: NanMode) -> Option<T>
+    where
+        T: SimdNanMode;
+}
+
+impl<T: SimdElement> SliceSimdExt<T> for [T] {
+    #[inline]
+    fn min_simd(&self) -> Option<T> {
+        T::slice_min(self)
+    }
+
+    #[inline]
+    fn max_simd(&self) -> Option<T> {
+        T::slice_max(self)
+    }
+
+    #[inline]
+    fn min_max_simd(&self) -> Option<(T, T)> {
+        T::slice_min_max(self)
+    }
+
+    #[inline]
+    fn find_simd(&self, needle: T) -> Option<usize>
+    where
+        T: SimdSearch,
+    {
+        T::slice_find(self, needle)
+    }
+
+    #[inline]
+    fn rfind_simd(&self, needle: T) -> Option<usize>
+    where
+        T: SimdReverseSearch,
+    {
+        T::slice_rfind(self, needle)
+    }
+
+    #[inline]
+    fn find_any2_simd(&self, a: T, b: T) -> Option<usize>
+    where
+        T: SimdFindAny,
+    {
+        T::slice_find_any2(self, a, b)
+    }
+
+    #[inline]
+    fn find_any3_simd(&self, a: T, b: T, c: T) -> Option<usize>
+    where
+        T: SimdFindAny,
+    {
+        T::slice_find_any3(self, a, b, c)
+    }
+
+    #[inline]
+    fn find_any_of_simd(&self, needles: &[T]) -> Option<usize>
+    where
+        T: SimdFindAny,
+    {
+        T::slice_find_any_of(self, needles)
+    }
+
+    #[inline]
+    fn find_subslice_simd(&self, needle: &[T]) -> Option<usize>
+    where
+        T: SimdFindSubslice,
+    {
+        T::slice_find_subslice(self, needle)
+    }
+
+    #[inline]
+    fn contains_subslice_simd(&self, needle: &[T]) -> bool
+    where
+        T: SimdFindSubslice,
+    {
+        T::slice_find_subslice(self, needle).is_some()
+    }
+
+    #[inline]
+    fn find_all_simd(&self, needle: T) -> FindAll<'_, T>
+    where
+        T: SimdFindAll,
+    {
+        FindAll::new(self, needle)
+    }
+
+    #[inline]
+    fn find_all_simd_into<P: Position>(&self, needle: T, positions: &mut Vec<P>)
+    where
+        T: SimdFindAll,
+    {
+        positions.extend(self.find_all_simd(needle).map(P::from_position));
+    }
+
+    #[inline]
+    fn contains_simd(&self, needle: T) -> bool
+    where
+        T: SimdSearch,
+    {
+        T::slice_contains(self, needle)
+    }
+
+    #[inline]
+    fn count_simd(&self, element: T) -> usize
+    where
+        T: SimdSearch,
+    {
+        T::slice_count(self, element)
+    }
+
+    #[inline]
+    fn all_eq_simd(&self, value: T) -> bool
+    where
+        T: SimdUniform,
+    {
+        T::slice_all_eq(self, value)
+    }
+
+    #[inline]
+    fn is_zero_simd(&self) -> bool
+    where
+        T: SimdUniform,
+    {
+        T::slice_all_eq(self, T::ZERO)
+    }
+
+    #[inline]
+    fn count_in_range_simd(&self, lo: T, hi: T) -> usize
+    where
+        T: SimdRange,
+    {
+        T::slice_count_in_range(self, lo, hi)
+    }
+
+    #[inline]
+    fn find_first_in_range_simd(&self, lo: T, hi: T) -> Option<usize>
+    where
+        T: SimdRange,
+    {
+        T::slice_find_in_range(self, lo, hi)
+    }
+
+    #[inline]
+    fn any_in_range_simd(&self, lo: T, hi: T) -> bool
+    where
+        T: SimdRange,
+    {
+        T::slice_find_in_range(self, lo, hi).is_some()
+    }
+
+    #[inline]
+    fn find_gt_simd(&self, value: T) -> Option<usize>
+    where
+        T: SimdRange,
+    {
+        T::slice_find_in_range(self, T::successor(value)?, T::HIGHEST)
+    }
+
+    #[inline]
+    fn find_ge_simd(&self, value: T) -> Option<usize>
+    where
+        T: SimdRange,
+    {
+        T::slice_find_in_range(self, value, T::HIGHEST)
+    }
+
+    #[inline]
+    fn find_lt_simd(&self, value: T) -> Option<usize>
+    where
+        T: SimdRange,
+    {
+        T::slice_find_in_range(self, T::LOWEST, T::predecessor(value)?)
+    }
+
+    #[inline]
+    fn find_le_simd(&self, value: T) -> Option<usize>
+    where
+        T: SimdRange,
+    {
+        T::slice_find_in_range(self, T::LOWEST, value)
+    }
+
+    #[inline]
+    fn eq_simd(&self, other: &[T]) -> bool
+    where
+        T: SimdMismatch,
+    {
+        let array = self;
+        array.len() == other.len() && T::slice_mismatch(array, other).is_none()
+    }
+
+    #[inline]
+    fn mismatch_simd(&self, other: &[T]) -> Option<usize>
+    where
+        T: SimdMismatch,
+    {
+        T::slice_mismatch(self, other)
+    }
+
+    #[inline]
+    fn cmp_simd(&self, other: &[T]) -> Ordering
+    where
+        T: SimdMismatch + Ord,
+    {
+        let array = self;
+
+        match T::slice_mismatch(array, other) {
+            Some(i) if i < array.len() && i < other.len() => array[i].cmp(&other[i]),
+            _ => array.len().cmp(&other.len()),
+        }
+    }
+
+    #[inline]
+    fn sum_simd(&self) -> T::Sum
+    where
+        T: SimdSum,
+    {
+        T::slice_sum(self)
+    }
+
+    #[inline]
+    fn sum_simd_with(&self, mode: SumMode) -> T::Sum
+    where
+        T: SimdSumMode,
+    {
+        T::slice_sum_with(self, mode)
+    }
+
+    #[inline]
+    fn argmin_simd(&self) -> Option<usize>
+    where
+        T: SimdArgMinMax,
+    {
+        T::slice_argmin(self)
+    }
+
+    #[inline]
+    fn argmax_simd(&self) -> Option<usize>
+    where
+        T: SimdArgMinMax,
+    {
+        T::slice_argmax(self)
+    }
+
+    #[inline]
+    fn min_simd_with(&self, mode: NanMode) -> Option<T>
+    where
+        T: SimdNanMode,
+    {
+        T::slice_min_with(self, mode)
+    }
+
+    #[inline]
+    fn max_simd_with(&self, mode: NanMode) -> Option<T>
+    where
+        T: SimdNanMode,
+    {
+        T::slice_max_with(self, mode)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+
+    /// Generic code only needs the element bounds to use the operations.
+    fn min_max_generic<T: SimdElement + Ord>(xs: &[T]) -> (Option<T>, Option<T>) {
+        (xs.min_simd(), xs.max_simd())
+    }
+
+    fn count_generic<T: SimdSearch>(xs: &[T], needle: T) -> (Option<usize>, bool, usize) {
+        (
+            xs.find_simd(needle),
+            xs.contains_simd(needle),
+            xs.count_simd(needle),
+        )
+    }
+
+    #[test]
+    fn test_slice_generic() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let values: Vec<u64> = (0..200).map(|_| rng.next_u64() % 50).collect();
+
+        macro_rules! check {
+            ($($ty:ty),*) => {
+                $(
+                    let vec: Vec<$ty> = values.iter().map(|i| *i as $ty).collect();
+
+                    assert_eq!(
+                        min_max_generic(&vec),
+                        (vec.iter().min().copied(), vec.iter().max().copied())
+                    );
+                    assert_eq!(
+                        vec.min_max_simd(),
+                        vec.iter().min().copied().zip(vec.iter().max().copied())
+                    );
+                    assert_eq!(
+                        count_generic(&vec, vec[100]),
+                        (
+                            vec.iter().position(|i| *i == vec[100]),
+                            true,
+                            vec.iter().filter(|i| **i == vec[100]).count()
+                        )
+                    );
+                )*
+            };
+        }
+
+        check!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+        let floats: Vec<f64> = values.iter().map(|i| *i as f64).collect();
+        assert_eq!(count_generic(&floats, 60.0), (None, false, 0));
+        assert_eq!(floats.sum_simd(), floats.iter().sum::<f64>());
+        assert_eq!(floats.min_max_simd(), Some((0.0, 49.0)));
+
+        let floats: Vec<f32> = values.iter().map(|i| *i as f32).collect();
+        assert_eq!(floats.min_simd(), floats.min_simd_with(NanMode::Ignore));
+        assert_eq!(floats.max_simd(), Some(49.0));
+        assert_eq!(
+            floats.min_max_simd(),
+            floats.min_simd().zip(floats.max_simd())
+        );
+
+        let mut other = floats.clone();
+        assert!(floats.eq_simd(&other));
+        other[150] = f32::NAN;
+        assert_eq!(floats.mismatch_simd(&other), Some(150));
+        assert!(!floats.eq_simd(&other[..199]));
+    }
+
+    #[test]
+    fn test_slice_cmp() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let keys: Vec<Vec<u8>> = (0..200)
+            .map(|_| {
+                let len = rng.next_u32() as usize % 100;
+                // Few distinct bytes, so keys often share long prefixes.
+                (0..len).map(|_| (rng.next_u32() % 3) as u8).collect()
+            })
+            .collect();
+
+        for a in &keys {
+            for b in keys.iter().step_by(3) {
+                assert_eq!(a.cmp_simd(b), a.cmp(b), "{a:?} <=> {b:?}");
+            }
+
+            assert_eq!(a.cmp_simd(a), Ordering::Equal);
+        }
+
+        assert_eq!(b"ab".cmp_simd(b"abc"), Ordering::Less);
+        assert_eq!([2u32, 1].cmp_simd(&[1, 5, 6]), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_slice_threshold_search() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        macro_rules! check {
+            ($vec:expr, $values:expr) => {
+                let vec = $vec;
+
+                for value in $values {
+                    assert_eq!(vec.find_gt_simd(value), vec.iter().position(|i| *i > value));
+                    assert_eq!(
+                        vec.find_ge_simd(value),
+                        vec.iter().position(|i| *i >= value)
+                    );
+                    assert_eq!(vec.find_lt_simd(value), vec.iter().position(|i| *i < value));
+                    assert_eq!(
+                        vec.find_le_simd(value),
+                        vec.iter().position(|i| *i <= value)
+                    );
+                }
+            };
+        }
+
+        let u8: Vec<u8> = (0..300).map(|_| rng.next_u32() as u8 / 2 + 64).collect();
+        check!(&u8, [0, 63, 64, 100, 191, 200, u8::MAX]);
+
+        let u32: Vec<u32> = (0..300).map(|_| rng.next_u32() / 2 + (1 << 30)).collect();
+        check!(&u32, [0, 1 << 30, 1 << 31, u32::MAX - 1, u32::MAX]);
+        check!(&u32[..0], [0, u32::MAX]);
+
+        let mut f32: Vec<f32> = (0..300).map(|_| rng.next_u32() as i32 as f32).collect();
+        f32[100] = f32::NAN;
+        f32[150] = -0.0;
+        f32[299] = f32::INFINITY;
+        check!(
+            &f32,
+            [
+                0.0,
+                -0.0,
+                f32::NAN,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                f32[200]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_slice_unsized() {
+        let array = [3u32, 1, 2];
+        let slice: &[u32] = &array;
+
+        assert_eq!(array.max_simd(), Some(3));
+        assert_eq!(slice.min_simd(), Some(1));
+        assert_eq!(array.min_max_simd(), Some((1, 3)));
+        assert_eq!(slice[1..].find_simd(2), Some(1));
+        assert_eq!(b"a,b,c".rfind_simd(b','), Some(3));
+        assert_eq!(b"a,b,c".find_subslice_simd(b"b,c"), Some(2));
+        assert!(!b"a,b,c".contains_subslice_simd(b"c,"));
+        assert!(array.eq_simd(&[3, 1, 2]));
+        assert_eq!(slice.mismatch_simd(&[3, 1]), Some(2));
+        assert_eq!(array.count_in_range_simd(2, 3), 2);
+        assert_eq!(slice.find_first_in_range_simd(1, 2), Some(1));
+        assert!(!array.any_in_range_simd(4, u32::MAX));
+        assert!([7u32; 5].all_eq_simd(7));
+        assert!(!slice.is_zero_simd());
+        assert!(b"".is_zero_simd());
+        assert_eq!(b"key=\"a,b\"\n".find_any3_simd(b',', b'"', b'\n'), Some(4));
+    }
+}
@@ -0,0 +1,178 @@
+use std::arch::x86_64::*;
+
+use crate::float::{NanMode, finish_min_max_f32};
+use crate::x86_64::array::avx512::lanes_mask16;
+
+/// Returns the smallest item in the array, or `None` if the array was empty. NaN values are handled as described
+/// by `mode`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn min_avx512(array: &[f32], mode: NanMode) -> Option<f32> {
+    // Passing constants lets the compiler remove the unused branches of `min_max_avx512`.
+    match mode {
+        NanMode::Ignore => min_max_avx512(array, false, NanMode::Ignore),
+        NanMode::Propagate => min_max_avx512(array, false, NanMode::Propagate),
+        NanMode::TotalOrder => min_max_avx512(array, false, NanMode::TotalOrder),
+    }
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty. NaN values are handled as described
+/// by `mode`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn max_avx512(array: &[f32], mode: NanMode) -> Option<f32> {
+    match mode {
+        NanMode::Ignore => min_max_avx512(array, true, NanMode::Ignore),
+        NanMode::Propagate => min_max_avx512(array, true, NanMode::Propagate),
+        NanMode::TotalOrder => min_max_avx512(array, true, NanMode::TotalOrder),
+    }
+}
+
+/// Reduces the array to its minimum or maximum by comparing the total order keys of the items, which orders signed
+/// zeros and NaNs independently of the operand order.
+#[inline]
+#[target_feature(enable = "avx512f")]
+fn min_max_avx512(array: &[f32], max: bool, mode: NanMode) -> Option<f32> {
+    const STEP: usize = 64;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut lkeys1 = _mm512_set1_epi32(if max { i32::MIN } else { i32::MAX });
+    let mut lkeys2 = lkeys1;
+    let mut lkeys3 = lkeys1;
+    let mut lkeys4 = lkeys1;
+    let mut nans = 0;
+
+    unsafe {
+        while i < iterr {
+            let ptr = array.as_ptr().add(i);
+
+            let current = _mm512_loadu_ps(ptr);
+            let current2 = _mm512_loadu_ps(ptr.add(16));
+            let current3 = _mm512_loadu_ps(ptr.add(32));
+            let current4 = _mm512_loadu_ps(ptr.add(48));
+
+            lkeys1 = accumulate(lkeys1, &mut nans, current, u16::MAX, max, mode);
+            lkeys2 = accumulate(lkeys2, &mut nans, current2, u16::MAX, max, mode);
+            lkeys3 = accumulate(lkeys3, &mut nans, current3, u16::MAX, max, mode);
+            lkeys4 = accumulate(lkeys4, &mut nans, current4, u16::MAX, max, mode);
+
+            i += STEP;
+        }
+
+        // Remaining vectors. Lanes that are not loaded keep the current key.
+        while i < len {
+            let lanes = lanes_mask16(len - i);
+            let current = _mm512_maskz_loadu_ps(lanes, array.as_ptr().add(i));
+            lkeys1 = accumulate(lkeys1, &mut nans, current, lanes, max, mode);
+
+            i += 16;
+        }
+    }
+
+    let key = if max {
+        let m1 = _mm512_max_epi32(lkeys1, lkeys2);
+        let m2 = _mm512_max_epi32(lkeys3, lkeys4);
+        _mm512_reduce_max_epi32(_mm512_max_epi32(m1, m2))
+    } else {
+        let m1 = _mm512_min_epi32(lkeys1, lkeys2);
+        let m2 = _mm512_min_epi32(lkeys3, lkeys4);
+        _mm512_reduce_min_epi32(_mm512_min_epi32(m1, m2))
+    };
+
+    finish_min_max_f32(array, len, key, nans != 0, max, mode)
+}
+
+/// Merges the total order keys of the `lanes` of `current` into `keys`, and records its NaN lanes in `nans`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+fn accumulate(
+    keys: __m512i,
+    nans: &mut u16,
+    current: __m512,
+    lanes: __mmask16,
+    max: bool,
+    mode: NanMode,
+) -> __m512i {
+    let bits = _mm512_castps_si512(current);
+    let current_keys =
+        _mm512_xor_si512(bits, _mm512_srli_epi32::<1>(_mm512_srai_epi32::<31>(bits)));
+
+    let mut lanes = lanes;
+
+    if mode != NanMode::TotalOrder {
+        let is_nan = _mm512_mask_cmp_ps_mask::<_CMP_UNORD_Q>(lanes, current, current);
+        *nans |= is_nan;
+
+        if mode == NanMode::Ignore {
+            lanes &= !is_nan;
+        }
+    }
+
+    if max {
+        _mm512_mask_max_epi32(keys, lanes, keys, current_keys)
+    } else {
+        _mm512_mask_min_epi32(keys, lanes, keys, current_keys)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+    use crate::float::test::{NAN_MODES, assert_same_f32, random_f32_with_specials};
+    use crate::original::array::{max_iter_f32, min_iter_f32};
+
+    #[test]
+    fn test_array_f32_min_max_fuzzy() {
+        if !is_x86_feature_detected!("avx512f") {
+            return;
+        }
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in (0..5000).step_by(13) {
+            for _ in 0..10 {
+                let vec: Vec<_> = (0..len)
+                    .map(|_| rng.next_u32() as i32 as f32 / 102.0)
+                    .collect();
+
+                let simd_min = unsafe { min_avx512(&vec, NanMode::Ignore) };
+                assert_eq!(simd_min, min_iter_f32(&vec, NanMode::Ignore));
+
+                let simd_max = unsafe { max_avx512(&vec, NanMode::Ignore) };
+                assert_eq!(simd_max, max_iter_f32(&vec, NanMode::Ignore));
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f32_min_max_special_values_fuzzy() {
+        if !is_x86_feature_detected!("avx512f") {
+            return;
+        }
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 2, 7, 15, 16, 17, 63, 64, 65, 100, 513, 1030] {
+            for special_rate in [1, 2, 16, 1000] {
+                let vec = random_f32_with_specials(&mut rng, len, special_rate);
+
+                for mode in NAN_MODES {
+                    assert_same_f32(unsafe { min_avx512(&vec, mode) }, min_iter_f32(&vec, mode));
+                    assert_same_f32(unsafe { max_avx512(&vec, mode) }, max_iter_f32(&vec, mode));
+                }
+            }
+        }
+    }
+}
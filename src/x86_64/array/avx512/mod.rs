@@ -0,0 +1,23 @@
+pub mod f32_impl;
+pub mod u32_impl;
+pub mod u8_impl;
+
+/// Returns a mask selecting the first `remaining` lanes of a vector with 16 lanes.
+#[inline]
+pub(crate) fn lanes_mask16(remaining: usize) -> u16 {
+    if remaining >= 16 {
+        u16::MAX
+    } else {
+        (1 << remaining) - 1
+    }
+}
+
+/// Returns a mask selecting the first `remaining` lanes of a vector with 64 lanes.
+#[inline]
+pub(crate) fn lanes_mask64(remaining: usize) -> u64 {
+    if remaining >= 64 {
+        u64::MAX
+    } else {
+        (1 << remaining) - 1
+    }
+}
@@ -0,0 +1,319 @@
+use std::arch::x86_64::*;
+
+use crate::x86_64::array::avx512::lanes_mask16;
+
+/// Returns `true` if `needle` is an element in the given array.
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn contains_avx512(array: &[u32], needle: u32) -> bool {
+    const STEP: usize = 64;
+
+    let needle_mask = _mm512_set1_epi32(needle as i32);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    unsafe {
+        while i < vectorized_part {
+            let ptr = array.as_ptr().add(i);
+
+            let curr_items = _mm512_loadu_epi32(ptr.cast());
+            let curr_items_p1 = _mm512_loadu_epi32(ptr.add(16).cast());
+            let curr_items_p2 = _mm512_loadu_epi32(ptr.add(32).cast());
+            let curr_items_p3 = _mm512_loadu_epi32(ptr.add(48).cast());
+
+            let compared = _mm512_cmpeq_epi32_mask(needle_mask, curr_items);
+            let compared1 = _mm512_cmpeq_epi32_mask(needle_mask, curr_items_p1);
+            let compared2 = _mm512_cmpeq_epi32_mask(needle_mask, curr_items_p2);
+            let compared3 = _mm512_cmpeq_epi32_mask(needle_mask, curr_items_p3);
+
+            if (compared | compared1 | compared2 | compared3) != 0 {
+                return true;
+            }
+
+            i += STEP;
+        }
+
+        // Remaining vectors, the last one only being partially loaded.
+        while i < len {
+            let lanes = lanes_mask16(len - i);
+            let curr_items = _mm512_maskz_loadu_epi32(lanes, array.as_ptr().add(i).cast());
+
+            if _mm512_mask_cmpeq_epi32_mask(lanes, needle_mask, curr_items) != 0 {
+                return true;
+            }
+
+            i += 16;
+        }
+    }
+
+    false
+}
+
+/// Returns the position of `needle` in `array` if the array contains it.
+/// If there are multiple occurrences of `needle` in `array`, the first index gets returned.
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn find_avx512(array: &[u32], needle: u32) -> Option<usize> {
+    const STEP: usize = 64;
+
+    let needle_mask = _mm512_set1_epi32(needle as i32);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    unsafe {
+        while i < vectorized_part {
+            let ptr = array.as_ptr().add(i);
+
+            let curr_items = _mm512_loadu_epi32(ptr.cast());
+            let curr_items_p1 = _mm512_loadu_epi32(ptr.add(16).cast());
+            let curr_items_p2 = _mm512_loadu_epi32(ptr.add(32).cast());
+            let curr_items_p3 = _mm512_loadu_epi32(ptr.add(48).cast());
+
+            let compared = _mm512_cmpeq_epi32_mask(needle_mask, curr_items) as u64;
+            let compared1 = _mm512_cmpeq_epi32_mask(needle_mask, curr_items_p1) as u64;
+            let compared2 = _mm512_cmpeq_epi32_mask(needle_mask, curr_items_p2) as u64;
+            let compared3 = _mm512_cmpeq_epi32_mask(needle_mask, curr_items_p3) as u64;
+
+            let mask = compared | (compared1 << 16) | (compared2 << 32) | (compared3 << 48);
+            if mask != 0 {
+                return Some(i + mask.trailing_zeros() as usize);
+            }
+
+            i += STEP;
+        }
+
+        // Remaining vectors, the last one only being partially loaded.
+        while i < len {
+            let lanes = lanes_mask16(len - i);
+            let curr_items = _mm512_maskz_loadu_epi32(lanes, array.as_ptr().add(i).cast());
+
+            let mask = _mm512_mask_cmpeq_epi32_mask(lanes, needle_mask, curr_items);
+            if mask != 0 {
+                return Some(i + mask.trailing_zeros() as usize);
+            }
+
+            i += 16;
+        }
+    }
+
+    None
+}
+
+/// Returns the amount of occurrences of `element` in `array`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn count_avx512(array: &[u32], element: u32) -> usize {
+    const STEP: usize = 64;
+
+    let needle_mask = _mm512_set1_epi32(element as i32);
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut count = 0;
+
+    unsafe {
+        while i < iterr {
+            let ptr = array.as_ptr().add(i);
+
+            let curr_items = _mm512_loadu_epi32(ptr.cast());
+            let curr_items_2 = _mm512_loadu_epi32(ptr.add(16).cast());
+            let curr_items_3 = _mm512_loadu_epi32(ptr.add(32).cast());
+            let curr_items_4 = _mm512_loadu_epi32(ptr.add(48).cast());
+
+            let cmp = _mm512_cmpeq_epi32_mask(curr_items, needle_mask);
+            let cmp2 = _mm512_cmpeq_epi32_mask(curr_items_2, needle_mask);
+            let cmp3 = _mm512_cmpeq_epi32_mask(curr_items_3, needle_mask);
+            let cmp4 = _mm512_cmpeq_epi32_mask(curr_items_4, needle_mask);
+
+            count += (cmp.count_ones() + cmp2.count_ones() + cmp3.count_ones() + cmp4.count_ones())
+                as usize;
+
+            i += STEP;
+        }
+
+        // Remaining vectors, the last one only being partially loaded.
+        while i < len {
+            let lanes = lanes_mask16(len - i);
+            let curr_items = _mm512_maskz_loadu_epi32(lanes, array.as_ptr().add(i).cast());
+
+            count +=
+                _mm512_mask_cmpeq_epi32_mask(lanes, curr_items, needle_mask).count_ones() as usize;
+
+            i += 16;
+        }
+    }
+
+    count
+}
+
+/// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn min_avx512(array: &[u32]) -> Option<u32> {
+    const STEP: usize = 64;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut lmins1 = _mm512_set1_epi32(u32::MAX as i32);
+    let mut lmins2 = _mm512_set1_epi32(u32::MAX as i32);
+    let mut lmins3 = _mm512_set1_epi32(u32::MAX as i32);
+    let mut lmins4 = _mm512_set1_epi32(u32::MAX as i32);
+
+    unsafe {
+        while i < iterr {
+            let ptr = array.as_ptr().add(i);
+
+            let current = _mm512_loadu_epi32(ptr.cast());
+            let current2 = _mm512_loadu_epi32(ptr.add(16).cast());
+            let current3 = _mm512_loadu_epi32(ptr.add(32).cast());
+            let current4 = _mm512_loadu_epi32(ptr.add(48).cast());
+
+            lmins1 = _mm512_min_epu32(current, lmins1);
+            lmins2 = _mm512_min_epu32(current2, lmins2);
+            lmins3 = _mm512_min_epu32(current3, lmins3);
+            lmins4 = _mm512_min_epu32(current4, lmins4);
+
+            i += STEP;
+        }
+
+        // Remaining vectors. Lanes that are not loaded keep the current minimum.
+        while i < len {
+            let lanes = lanes_mask16(len - i);
+            let current = _mm512_mask_loadu_epi32(lmins1, lanes, array.as_ptr().add(i).cast());
+            lmins1 = _mm512_min_epu32(current, lmins1);
+
+            i += 16;
+        }
+    }
+
+    let m1 = _mm512_min_epu32(lmins1, lmins2);
+    let m2 = _mm512_min_epu32(lmins3, lmins4);
+    let lmins = _mm512_min_epu32(m1, m2);
+
+    Some(_mm512_reduce_min_epu32(lmins))
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn max_avx512(array: &[u32]) -> Option<u32> {
+    const STEP: usize = 64;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut lmax1 = _mm512_setzero_si512();
+    let mut lmax2 = _mm512_setzero_si512();
+    let mut lmax3 = _mm512_setzero_si512();
+    let mut lmax4 = _mm512_setzero_si512();
+
+    unsafe {
+        while i < iterr {
+            let ptr = array.as_ptr().add(i);
+
+            let current = _mm512_loadu_epi32(ptr.cast());
+            let current2 = _mm512_loadu_epi32(ptr.add(16).cast());
+            let current3 = _mm512_loadu_epi32(ptr.add(32).cast());
+            let current4 = _mm512_loadu_epi32(ptr.add(48).cast());
+
+            lmax1 = _mm512_max_epu32(current, lmax1);
+            lmax2 = _mm512_max_epu32(current2, lmax2);
+            lmax3 = _mm512_max_epu32(current3, lmax3);
+            lmax4 = _mm512_max_epu32(current4, lmax4);
+
+            i += STEP;
+        }
+
+        // Remaining vectors. Lanes that are not loaded keep the current maximum.
+        while i < len {
+            let lanes = lanes_mask16(len - i);
+            let current = _mm512_mask_loadu_epi32(lmax1, lanes, array.as_ptr().add(i).cast());
+            lmax1 = _mm512_max_epu32(current, lmax1);
+
+            i += 16;
+        }
+    }
+
+    let m1 = _mm512_max_epu32(lmax1, lmax2);
+    let m2 = _mm512_max_epu32(lmax3, lmax4);
+    let lmax = _mm512_max_epu32(m1, m2);
+
+    Some(_mm512_reduce_max_epu32(lmax))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_array_find_count_fuzzy() {
+        if !is_x86_feature_detected!("avx512f") {
+            return;
+        }
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 15, 16, 17, 63, 64, 65, 127, 128, 200, 513] {
+            for _ in 0..20 {
+                // Small value range to produce duplicates.
+                let vec: Vec<u32> = (0..len).map(|_| rng.next_u32() % 64).collect();
+                let needle = rng.next_u32() % 64;
+
+                let simd_find = unsafe { find_avx512(&vec, needle) };
+                assert_eq!(simd_find, vec.iter().position(|i| *i == needle));
+
+                let simd_contains = unsafe { contains_avx512(&vec, needle) };
+                assert_eq!(simd_contains, vec.contains(&needle));
+
+                let simd_count = unsafe { count_avx512(&vec, needle) };
+                assert_eq!(simd_count, vec.iter().filter(|i| **i == needle).count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_fuzzy() {
+        if !is_x86_feature_detected!("avx512f") {
+            return;
+        }
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 15, 16, 17, 32, 64, 127, 128, 256, 513, 1024, 6256] {
+            let vec: Vec<u32> = (0..len).map(|_| rng.next_u32()).collect();
+
+            let simd_min = unsafe { min_avx512(&vec) };
+            assert_eq!(simd_min, vec.iter().min().copied());
+
+            let simd_max = unsafe { max_avx512(&vec) };
+            assert_eq!(simd_max, vec.iter().max().copied());
+        }
+    }
+}
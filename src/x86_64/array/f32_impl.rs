@@ -1,17 +1,55 @@
-use std::arch::x86_64::{
-    _mm256_loadu_ps, _mm256_max_ps, _mm256_min_ps, _mm256_set1_ps, _mm256_setzero_ps,
+use std::arch::x86_64::*;
+use std::mem::transmute;
+
+use crate::float::{KahanSum, NanMode, SumMode, finish_min_max_f32, total_order_key};
+use crate::original::array::{
+    argmax_iter_f32, argmin_iter_f32, count_in_range_iter_f32, find_in_range_iter_f32,
+    mismatch_iter_f32,
+};
+use crate::x86_64::simd_extensions::{
+    horizontal_max_i32_avx, horizontal_min_i32_avx, horizontal_sum_f32_avx,
+    negative_horizontal_sum_u32_avx, total_order_key_f32_avx,
 };
-use std::f32;
 
-use crate::original::array::{max_iter_f32, min_iter_f32};
-use crate::x86_64::simd_extensions::{horizontal_max_f32_avx, horizontal_min_f32_avx};
+/// Returns the smallest item in the array, or `None` if the array was empty. NaN values are handled as described
+/// by `mode`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_avx(array: &[f32], mode: NanMode) -> Option<f32> {
+    // Passing constants lets the compiler remove the unused branches of `extreme_avx`.
+    match mode {
+        NanMode::Ignore => extreme_avx(array, false, NanMode::Ignore),
+        NanMode::Propagate => extreme_avx(array, false, NanMode::Propagate),
+        NanMode::TotalOrder => extreme_avx(array, false, NanMode::TotalOrder),
+    }
+}
 
-/// Returns the smallest item in the array, or `None` if the array was empty.
+/// Returns the largest item in the array, or `None` if the array was empty. NaN values are handled as described
+/// by `mode`.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
-pub fn min_avx(array: &[f32]) -> Option<f32> {
+pub fn max_avx(array: &[f32], mode: NanMode) -> Option<f32> {
+    match mode {
+        NanMode::Ignore => extreme_avx(array, true, NanMode::Ignore),
+        NanMode::Propagate => extreme_avx(array, true, NanMode::Propagate),
+        NanMode::TotalOrder => extreme_avx(array, true, NanMode::TotalOrder),
+    }
+}
+
+/// Reduces the array to its minimum or maximum by comparing the total order keys of the items, which orders signed
+/// zeros and NaNs independently of the operand order.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn extreme_avx(array: &[f32], max: bool, mode: NanMode) -> Option<f32> {
     const STEP: usize = 32;
 
+    if array.is_empty() {
+        return None;
+    }
+
     let len = array.len();
     let m = len % STEP;
     let iterr = len - m;
@@ -20,50 +58,207 @@ pub fn min_avx(array: &[f32]) -> Option<f32> {
 
     let mut ptr = array.as_ptr();
 
-    let mut lmins1 = _mm256_set1_ps(f32::INFINITY);
-    let mut lmins2 = _mm256_set1_ps(f32::INFINITY);
-    let mut lmins3 = _mm256_set1_ps(f32::INFINITY);
-    let mut lmins4 = _mm256_set1_ps(f32::INFINITY);
+    let mut lkeys1 = _mm256_set1_epi32(if max { i32::MIN } else { i32::MAX });
+    let mut lkeys2 = lkeys1;
+    let mut lkeys3 = lkeys1;
+    let mut lkeys4 = lkeys1;
+    let mut nans = _mm256_setzero_ps();
 
     unsafe {
         while i < iterr {
-            let current = _mm256_loadu_ps(ptr.cast());
-            let current2 = _mm256_loadu_ps(ptr.add(8).cast());
-            let current3 = _mm256_loadu_ps(ptr.add(16).cast());
-            let current4 = _mm256_loadu_ps(ptr.add(24).cast());
+            let current = _mm256_loadu_ps(ptr);
+            let current2 = _mm256_loadu_ps(ptr.add(8));
+            let current3 = _mm256_loadu_ps(ptr.add(16));
+            let current4 = _mm256_loadu_ps(ptr.add(24));
 
-            lmins1 = _mm256_min_ps(current, lmins1);
-            lmins2 = _mm256_min_ps(current2, lmins2);
-            lmins3 = _mm256_min_ps(current3, lmins3);
-            lmins4 = _mm256_min_ps(current4, lmins4);
+            lkeys1 = accumulate(lkeys1, &mut nans, current, max, mode);
+            lkeys2 = accumulate(lkeys2, &mut nans, current2, max, mode);
+            lkeys3 = accumulate(lkeys3, &mut nans, current3, max, mode);
+            lkeys4 = accumulate(lkeys4, &mut nans, current4, max, mode);
 
             i += STEP;
             ptr = ptr.add(STEP);
         }
     }
 
-    let m1 = _mm256_min_ps(lmins1, lmins2);
-    let m2 = _mm256_min_ps(lmins3, lmins4);
-    let lmins = _mm256_min_ps(m1, m2);
-    let min = horizontal_min_f32_avx(lmins);
+    let key = if max {
+        let m1 = _mm256_max_epi32(lkeys1, lkeys2);
+        let m2 = _mm256_max_epi32(lkeys3, lkeys4);
+        horizontal_max_i32_avx(_mm256_max_epi32(m1, m2))
+    } else {
+        let m1 = _mm256_min_epi32(lkeys1, lkeys2);
+        let m2 = _mm256_min_epi32(lkeys3, lkeys4);
+        horizontal_min_i32_avx(_mm256_min_epi32(m1, m2))
+    };
+
+    let nan_seen = _mm256_movemask_ps(nans) != 0;
+    finish_min_max_f32(array, iterr, key, nan_seen, max, mode)
+}
 
-    if let Some(remainer_min) = min_iter_f32(&array[iterr..]) {
-        if min < remainer_min {
-            return Some(min);
-        } else {
-            return Some(remainer_min);
+/// Merges the total order keys of `current` into `keys`, and records its NaN lanes in `nans`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn accumulate(
+    keys: __m256i,
+    nans: &mut __m256,
+    current: __m256,
+    max: bool,
+    mode: NanMode,
+) -> __m256i {
+    let mut current_keys = total_order_key_f32_avx(current);
+
+    if mode != NanMode::TotalOrder {
+        let is_nan = _mm256_cmp_ps::<_CMP_UNORD_Q>(current, current);
+        *nans = _mm256_or_ps(*nans, is_nan);
+
+        if mode == NanMode::Ignore {
+            current_keys = _mm256_blendv_epi8(current_keys, keys, _mm256_castps_si256(is_nan));
         }
     }
 
-    Some(min)
+    if max {
+        _mm256_max_epi32(keys, current_keys)
+    } else {
+        _mm256_min_epi32(keys, current_keys)
+    }
 }
 
-/// Returns the largest item in the array, or `None` if the array was empty.
+/// Returns the smallest and the largest item in the array, or `None` if the array was empty. Both are determined
+/// in a single pass, so the array is only loaded once. NaN values are skipped as described by [`NanMode::Ignore`].
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
-pub fn max_avx(array: &[f32]) -> Option<f32> {
+pub fn min_max_avx(array: &[f32]) -> Option<(f32, f32)> {
     const STEP: usize = 32;
 
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm256_set1_epi32(i32::MAX);
+    let mut lmins2 = lmins1;
+    let mut lmax1 = _mm256_set1_epi32(i32::MIN);
+    let mut lmax2 = lmax1;
+    let mut nans = _mm256_setzero_ps();
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_ps(ptr);
+            let current2 = _mm256_loadu_ps(ptr.add(8));
+            let current3 = _mm256_loadu_ps(ptr.add(16));
+            let current4 = _mm256_loadu_ps(ptr.add(24));
+
+            (lmins1, lmax1) = accumulate_min_max(lmins1, lmax1, &mut nans, current);
+            (lmins2, lmax2) = accumulate_min_max(lmins2, lmax2, &mut nans, current2);
+            (lmins1, lmax1) = accumulate_min_max(lmins1, lmax1, &mut nans, current3);
+            (lmins2, lmax2) = accumulate_min_max(lmins2, lmax2, &mut nans, current4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let min_key = horizontal_min_i32_avx(_mm256_min_epi32(lmins1, lmins2));
+    let max_key = horizontal_max_i32_avx(_mm256_max_epi32(lmax1, lmax2));
+
+    let nan_seen = _mm256_movemask_ps(nans) != 0;
+    let min = finish_min_max_f32(array, iterr, min_key, nan_seen, false, NanMode::Ignore)?;
+    let max = finish_min_max_f32(array, iterr, max_key, nan_seen, true, NanMode::Ignore)?;
+
+    Some((min, max))
+}
+
+/// Merges the total order keys of `current` into `mins` and `maxs`, and records its NaN lanes in `nans`. NaN lanes
+/// keep the previous keys.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn accumulate_min_max(
+    mins: __m256i,
+    maxs: __m256i,
+    nans: &mut __m256,
+    current: __m256,
+) -> (__m256i, __m256i) {
+    let current_keys = total_order_key_f32_avx(current);
+
+    let is_nan = _mm256_cmp_ps::<_CMP_UNORD_Q>(current, current);
+    *nans = _mm256_or_ps(*nans, is_nan);
+    let is_nan = _mm256_castps_si256(is_nan);
+
+    (
+        _mm256_min_epi32(mins, _mm256_blendv_epi8(current_keys, mins, is_nan)),
+        _mm256_max_epi32(maxs, _mm256_blendv_epi8(current_keys, maxs, is_nan)),
+    )
+}
+
+/// Returns the sum of all items in the array, or `0.0` if the array was empty. The items are added up as described
+/// by `mode`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn sum_avx(array: &[f32], mode: SumMode) -> f32 {
+    match mode {
+        SumMode::Fast => fast_sum_avx(array),
+        SumMode::Kahan => kahan_sum_avx(array),
+    }
+}
+
+/// Adds the items to 32 partial sums, which are added up at the end.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn fast_sum_avx(array: &[f32]) -> f32 {
+    const STEP: usize = 32;
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut sum1 = _mm256_setzero_ps();
+    let mut sum2 = _mm256_setzero_ps();
+    let mut sum3 = _mm256_setzero_ps();
+    let mut sum4 = _mm256_setzero_ps();
+
+    unsafe {
+        while i < iterr {
+            sum1 = _mm256_add_ps(sum1, _mm256_loadu_ps(ptr));
+            sum2 = _mm256_add_ps(sum2, _mm256_loadu_ps(ptr.add(8)));
+            sum3 = _mm256_add_ps(sum3, _mm256_loadu_ps(ptr.add(16)));
+            sum4 = _mm256_add_ps(sum4, _mm256_loadu_ps(ptr.add(24)));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let s1 = _mm256_add_ps(sum1, sum2);
+    let s2 = _mm256_add_ps(sum3, sum4);
+    let sum = horizontal_sum_f32_avx(_mm256_add_ps(s1, s2));
+
+    sum + array[iterr..].iter().sum::<f32>()
+}
+
+/// Adds the items to 16 partial sums whose rounding errors are tracked like by [`KahanSum`], and adds up the partial
+/// sums and their errors at the end.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn kahan_sum_avx(array: &[f32]) -> f32 {
+    const STEP: usize = 16;
+
     let len = array.len();
     let m = len % STEP;
     let iterr = len - m;
@@ -72,51 +267,370 @@ pub fn max_avx(array: &[f32]) -> Option<f32> {
 
     let mut ptr = array.as_ptr();
 
-    let mut lmax1 = _mm256_setzero_ps();
-    let mut lmax2 = _mm256_setzero_ps();
-    let mut lmax3 = _mm256_setzero_ps();
-    let mut lmax4 = _mm256_setzero_ps();
+    let mut sum1 = _mm256_setzero_ps();
+    let mut sum2 = _mm256_setzero_ps();
+    let mut compensation1 = _mm256_setzero_ps();
+    let mut compensation2 = _mm256_setzero_ps();
 
     unsafe {
         while i < iterr {
-            let current = _mm256_loadu_ps(ptr.cast());
-            let current2 = _mm256_loadu_ps(ptr.add(8).cast());
-            let current3 = _mm256_loadu_ps(ptr.add(16).cast());
-            let current4 = _mm256_loadu_ps(ptr.add(24).cast());
+            let current = _mm256_loadu_ps(ptr);
+            let current2 = _mm256_loadu_ps(ptr.add(8));
 
-            lmax1 = _mm256_max_ps(current, lmax1);
-            lmax2 = _mm256_max_ps(current2, lmax2);
-            lmax3 = _mm256_max_ps(current3, lmax3);
-            lmax4 = _mm256_max_ps(current4, lmax4);
+            (sum1, compensation1) = kahan_add(sum1, compensation1, current);
+            (sum2, compensation2) = kahan_add(sum2, compensation2, current2);
 
             i += STEP;
             ptr = ptr.add(STEP);
         }
     }
 
-    let m1 = _mm256_max_ps(lmax1, lmax2);
-    let m2 = _mm256_max_ps(lmax3, lmax4);
-    let lmins = _mm256_max_ps(m1, m2);
-    let max = horizontal_max_f32_avx(lmins);
+    // Safety: we can safely transmute a __m256 to [f32; 8]
+    let sums: [[f32; 8]; 2] = unsafe { transmute((sum1, sum2)) };
+    let compensations: [[f32; 8]; 2] = unsafe { transmute((compensation1, compensation2)) };
+
+    let mut sum = KahanSum::default();
+
+    for partial_sum in sums.as_flattened() {
+        sum.add(*partial_sum);
+    }
+
+    // The compensations hold the negated errors of the partial sums.
+    for compensation in compensations.as_flattened() {
+        sum.add(-*compensation);
+    }
+
+    for item in &array[iterr..] {
+        sum.add(*item);
+    }
+
+    sum.total()
+}
+
+/// Adds `current` to `sum` like [`KahanSum::add`] does for every lane, tracking the errors in `compensation`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn kahan_add(sum: __m256, compensation: __m256, current: __m256) -> (__m256, __m256) {
+    let corrected = _mm256_sub_ps(current, compensation);
+    let new_sum = _mm256_add_ps(sum, corrected);
+    let error = _mm256_sub_ps(_mm256_sub_ps(new_sum, sum), corrected);
+
+    // Subtracting a non finite sum from itself results in NaN. Such lanes don't need a compensation anymore.
+    let difference = _mm256_sub_ps(new_sum, new_sum);
+    let finite = _mm256_cmp_ps::<_CMP_ORD_Q>(difference, difference);
+
+    (new_sum, _mm256_and_ps(error, finite))
+}
+
+/// Returns the index of the first smallest item in the array, or `None` if the array was empty. NaN values are
+/// skipped as described by [`NanMode::Ignore`], so the index of the first item is returned if all of them are NaN.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn argmin_avx(array: &[f32]) -> Option<usize> {
+    arg_extreme_avx(array, false)
+}
+
+/// Returns the index of the first largest item in the array, or `None` if the array was empty. NaN values are
+/// skipped as described by [`NanMode::Ignore`], so the index of the first item is returned if all of them are NaN.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn argmax_avx(array: &[f32]) -> Option<usize> {
+    arg_extreme_avx(array, true)
+}
 
-    if let Some(remainer_max) = max_iter_f32(&array[iterr..]) {
-        if max > remainer_max {
-            return Some(max);
+/// Finds the first minimum or maximum by keeping the extreme total order key of every lane together with the index
+/// it was found at.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn arg_extreme_avx(array: &[f32], max: bool) -> Option<usize> {
+    const STEP: usize = 32;
+
+    // The indices are tracked as 32 bit integers.
+    if array.len() > u32::MAX as usize {
+        return if max {
+            argmax_iter_f32(array)
         } else {
-            return Some(remainer_max);
+            argmin_iter_f32(array)
+        };
+    }
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    // Only NaN has this key, so it is never the key of a found item.
+    let init_key = if max { i32::MIN } else { i32::MAX };
+    let mut best = (init_key, usize::MAX);
+
+    if iterr > 0 {
+        let mut i = 0;
+        let mut ptr = array.as_ptr();
+
+        let lane_indices = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+        let mut indices1 = lane_indices;
+        let mut indices2 = _mm256_add_epi32(lane_indices, _mm256_set1_epi32(8));
+        let mut indices3 = _mm256_add_epi32(lane_indices, _mm256_set1_epi32(16));
+        let mut indices4 = _mm256_add_epi32(lane_indices, _mm256_set1_epi32(24));
+        let (mut current_indices1, mut current_indices2) = (indices1, indices2);
+        let (mut current_indices3, mut current_indices4) = (indices3, indices4);
+        let step = _mm256_set1_epi32(STEP as i32);
+
+        let init = _mm256_set1_epi32(init_key);
+        let mut extremes1 = init;
+        let mut extremes2 = init;
+        let mut extremes3 = init;
+        let mut extremes4 = init;
+
+        unsafe {
+            while i < iterr {
+                let current = _mm256_loadu_ps(ptr);
+                let current2 = _mm256_loadu_ps(ptr.add(8));
+                let current3 = _mm256_loadu_ps(ptr.add(16));
+                let current4 = _mm256_loadu_ps(ptr.add(24));
+
+                (extremes1, indices1) =
+                    update_extremes(extremes1, indices1, current, current_indices1, max);
+                (extremes2, indices2) =
+                    update_extremes(extremes2, indices2, current2, current_indices2, max);
+                (extremes3, indices3) =
+                    update_extremes(extremes3, indices3, current3, current_indices3, max);
+                (extremes4, indices4) =
+                    update_extremes(extremes4, indices4, current4, current_indices4, max);
+
+                current_indices1 = _mm256_add_epi32(current_indices1, step);
+                current_indices2 = _mm256_add_epi32(current_indices2, step);
+                current_indices3 = _mm256_add_epi32(current_indices3, step);
+                current_indices4 = _mm256_add_epi32(current_indices4, step);
+
+                i += STEP;
+                ptr = ptr.add(STEP);
+            }
         }
+
+        // Safety: we can safely transmute a __m256i to [i32; 8]
+        let extremes: [[i32; 8]; 4] =
+            unsafe { transmute([extremes1, extremes2, extremes3, extremes4]) };
+        let indices: [[u32; 8]; 4] = unsafe { transmute([indices1, indices2, indices3, indices4]) };
+
+        for (key, index) in extremes.as_flattened().iter().zip(indices.as_flattened()) {
+            let index = *index as usize;
+
+            let better = if *key == best.0 {
+                index < best.1
+            } else {
+                (*key > best.0) == max
+            };
+
+            if better {
+                best = (*key, index);
+            }
+        }
+    }
+
+    for (index, value) in array.iter().enumerate().skip(iterr) {
+        let key = total_order_key(*value);
+
+        if !value.is_nan() && key != best.0 && (key > best.0) == max {
+            best = (key, index);
+        }
+    }
+
+    // All items are NaN.
+    if best.0 == init_key {
+        return Some(0);
     }
 
-    Some(max)
+    Some(best.1)
+}
+
+/// Keeps the extreme total order key of `extremes` and `current` in every lane, together with the index it was found
+/// at. Equal items keep the earlier index, NaN items are skipped.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn update_extremes(
+    extremes: __m256i,
+    indices: __m256i,
+    current: __m256,
+    current_indices: __m256i,
+    max: bool,
+) -> (__m256i, __m256i) {
+    let keys = total_order_key_f32_avx(current);
+
+    let better = if max {
+        _mm256_cmpgt_epi32(keys, extremes)
+    } else {
+        _mm256_cmpgt_epi32(extremes, keys)
+    };
+    let is_nan = _mm256_castps_si256(_mm256_cmp_ps::<_CMP_UNORD_Q>(current, current));
+    let better = _mm256_andnot_si256(is_nan, better);
+
+    (
+        _mm256_blendv_epi8(extremes, keys, better),
+        _mm256_blendv_epi8(indices, current_indices, better),
+    )
+}
+
+/// Returns the position of the first item that differs between `array` and `other`, or the length of the shorter
+/// one if it is a prefix of the longer one. Returns `None` if both are equal.
+///
+/// Items are compared like `==` does, so NaN values never match and `-0.0` matches `0.0`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn mismatch_avx(array: &[f32], other: &[f32]) -> Option<usize> {
+    const STEP: usize = 32;
+
+    let len = array.len().min(other.len());
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let ptr = array.as_ptr();
+    let other_ptr = other.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let mut compared = [_mm256_setzero_ps(); 4];
+            for (j, compared) in compared.iter_mut().enumerate() {
+                let curr_items = _mm256_loadu_ps(ptr.add(i + j * 8));
+                let other_items = _mm256_loadu_ps(other_ptr.add(i + j * 8));
+                *compared = _mm256_cmp_ps::<_CMP_EQ_OQ>(curr_items, other_items);
+            }
+
+            let equal = _mm256_and_ps(
+                _mm256_and_ps(compared[0], compared[1]),
+                _mm256_and_ps(compared[2], compared[3]),
+            );
+
+            if _mm256_movemask_ps(equal) != 0xFF {
+                for (j, compared) in compared.iter().enumerate() {
+                    let mask = !_mm256_movemask_ps(*compared) as u32 & 0xFF;
+                    if mask != 0 {
+                        return Some(mask.trailing_zeros() as usize + i + j * 8);
+                    }
+                }
+            }
+
+            i += STEP;
+        }
+    }
+
+    mismatch_iter_f32(&array[i..], &other[i..]).map(|position| position + i)
+}
+
+/// Returns a mask of the items of `items` that lie within `lo..=hi`. NaN values are never within the range.
+#[inline]
+#[target_feature(enable = "avx")]
+fn in_range_avx(items: __m256, lo: __m256, hi: __m256) -> __m256 {
+    _mm256_and_ps(
+        _mm256_cmp_ps::<_CMP_GE_OQ>(items, lo),
+        _mm256_cmp_ps::<_CMP_LE_OQ>(items, hi),
+    )
+}
+
+/// Returns the amount of items within `lo..=hi`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn count_in_range_avx(array: &[f32], lo: f32, hi: f32) -> usize {
+    const STEP: usize = 32;
+
+    let lo_mask = _mm256_set1_ps(lo);
+    let hi_mask = _mm256_set1_ps(hi);
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    // Matching lanes are all ones (-1), so the sums count down.
+    let mut sums = [_mm256_setzero_si256(); 4];
+
+    unsafe {
+        while i < iterr {
+            for (j, sum) in sums.iter_mut().enumerate() {
+                let curr_items = _mm256_loadu_ps(ptr.add(j * 8));
+                let compared = in_range_avx(curr_items, lo_mask, hi_mask);
+                *sum = _mm256_add_epi32(*sum, _mm256_castps_si256(compared));
+            }
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let t1 = _mm256_add_epi32(sums[0], sums[1]);
+    let t2 = _mm256_add_epi32(sums[2], sums[3]);
+    let simd_res = negative_horizontal_sum_u32_avx(_mm256_add_epi32(t1, t2)) as usize;
+
+    simd_res + count_in_range_iter_f32(&array[iterr..], lo, hi)
+}
+
+/// Returns the position of the first item within `lo..=hi` if there is any.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_in_range_avx(array: &[f32], lo: f32, hi: f32) -> Option<usize> {
+    const STEP: usize = 32;
+
+    let lo_mask = _mm256_set1_ps(lo);
+    let hi_mask = _mm256_set1_ps(hi);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let mut compared = [_mm256_setzero_ps(); 4];
+            for (j, compared) in compared.iter_mut().enumerate() {
+                let curr_items = _mm256_loadu_ps(ptr.add(i + j * 8));
+                *compared = in_range_avx(curr_items, lo_mask, hi_mask);
+            }
+
+            let any = _mm256_or_ps(
+                _mm256_or_ps(compared[0], compared[1]),
+                _mm256_or_ps(compared[2], compared[3]),
+            );
+
+            if _mm256_movemask_ps(any) != 0 {
+                for (j, compared) in compared.iter().enumerate() {
+                    let mask = _mm256_movemask_ps(*compared) as u32;
+                    if mask != 0 {
+                        return Some(mask.trailing_zeros() as usize + i + j * 8);
+                    }
+                }
+            }
+
+            i += STEP;
+        }
+    }
+
+    find_in_range_iter_f32(&array[vectorized_part..], lo, hi)
+        .map(|position| position + vectorized_part)
 }
 
 #[cfg(test)]
 mod test {
     use rand::rngs::StdRng;
-    use rand::{Rng, SeedableRng};
+    use rand::{Rng, RngCore, SeedableRng};
 
     use super::*;
-    use crate::original::array::{max_iter_f32, min_iter_f32};
+    use crate::float::test::{NAN_MODES, assert_same_f32, random_f32_with_specials};
+    use crate::original::array::{max_iter_f32, min_iter_f32, sum_iter_f32};
 
     pub fn random_array_with_value(
         rng: &mut impl Rng,
@@ -144,13 +658,10 @@ mod test {
             })
             .collect();
 
-        match (value, index) {
-            (Some(val), Some(index)) => {
-                if index < len {
-                    vec[index] = val;
-                }
-            }
-            _ => (),
+        if let (Some(val), Some(index)) = (value, index)
+            && index < len
+        {
+            vec[index] = val;
         }
 
         vec
@@ -164,14 +675,197 @@ mod test {
             for _ in 0..10 {
                 let vec = random_array_with_value(&mut rng, len, None, None);
 
-                let simd_min = unsafe { min_avx(&vec) };
-                let real_min = min_iter_f32(&vec);
+                let simd_min = unsafe { min_avx(&vec, NanMode::Ignore) };
+                let real_min = min_iter_f32(&vec, NanMode::Ignore);
                 assert_eq!(simd_min, real_min);
 
-                let simd_max = unsafe { max_avx(&vec) };
-                let real_max = max_iter_f32(&vec);
+                let simd_max = unsafe { max_avx(&vec, NanMode::Ignore) };
+                let real_max = max_iter_f32(&vec, NanMode::Ignore);
                 assert_eq!(simd_max, real_max);
             }
         }
     }
+
+    #[test]
+    fn test_array_f32_min_max_negative() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [32, 64, 65, 127, 128, 513] {
+            let vec: Vec<_> = (0..len).map(|_| -(rng.next_u32() as f32) - 1.0).collect();
+
+            assert_eq!(
+                unsafe { min_avx(&vec, NanMode::Ignore) },
+                min_iter_f32(&vec, NanMode::Ignore)
+            );
+            assert_eq!(
+                unsafe { max_avx(&vec, NanMode::Ignore) },
+                max_iter_f32(&vec, NanMode::Ignore)
+            );
+        }
+    }
+
+    #[test]
+    fn test_array_f32_min_max_special_values_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 2, 7, 31, 32, 33, 64, 100, 513, 1030] {
+            for special_rate in [1, 2, 16, 1000] {
+                let vec = random_f32_with_specials(&mut rng, len, special_rate);
+
+                for mode in NAN_MODES {
+                    assert_same_f32(unsafe { min_avx(&vec, mode) }, min_iter_f32(&vec, mode));
+                    assert_same_f32(unsafe { max_avx(&vec, mode) }, max_iter_f32(&vec, mode));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f32_min_max_single_pass_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 2, 7, 31, 32, 33, 64, 100, 513, 1030] {
+            for special_rate in [1, 2, 16, 1000] {
+                let vec = random_f32_with_specials(&mut rng, len, special_rate);
+
+                let min_max = unsafe { min_max_avx(&vec) };
+                assert_same_f32(
+                    min_max.map(|(min, _)| min),
+                    min_iter_f32(&vec, NanMode::Ignore),
+                );
+                assert_same_f32(
+                    min_max.map(|(_, max)| max),
+                    max_iter_f32(&vec, NanMode::Ignore),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f32_arg_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 2, 7, 31, 32, 33, 64, 100, 513, 1030] {
+            for special_rate in [1, 2, 16, 1000] {
+                let vec = random_f32_with_specials(&mut rng, len, special_rate);
+
+                assert_eq!(unsafe { argmin_avx(&vec) }, argmin_iter_f32(&vec));
+                assert_eq!(unsafe { argmax_avx(&vec) }, argmax_iter_f32(&vec));
+            }
+
+            // Few distinct values, so the extremes occur several times.
+            let vec: Vec<f32> = (0..len).map(|_| (rng.next_u32() % 3) as f32).collect();
+            assert_eq!(unsafe { argmin_avx(&vec) }, argmin_iter_f32(&vec));
+            assert_eq!(unsafe { argmax_avx(&vec) }, argmax_iter_f32(&vec));
+        }
+
+        let vec = vec![f32::NAN; 100];
+        assert_eq!(unsafe { argmin_avx(&vec) }, Some(0));
+        assert_eq!(unsafe { argmax_avx(&vec) }, Some(0));
+    }
+
+    #[test]
+    fn test_array_f32_sum_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 15, 16, 17, 31, 32, 33, 513, 1030, 100_000] {
+            let vec: Vec<f32> = (0..len)
+                .map(|_| rng.next_u32() as f32 / 1e6 - 2e3)
+                .collect();
+            let exact = vec.iter().map(|i| *i as f64).sum::<f64>();
+            let magnitude = vec.iter().map(|i| i.abs() as f64).sum::<f64>();
+
+            let fast = unsafe { sum_avx(&vec, SumMode::Fast) } as f64;
+            assert!(
+                (fast - exact).abs() <= magnitude * 1e-5,
+                "{fast} != {exact}"
+            );
+
+            // The compensated sums are about as close as the rounding of the result allows.
+            let tolerance = exact.abs() * 2.0 * f32::EPSILON as f64 + magnitude * 1e-9;
+            for kahan in [
+                unsafe { sum_avx(&vec, SumMode::Kahan) },
+                sum_iter_f32(&vec, SumMode::Kahan),
+            ] {
+                assert!(
+                    (kahan as f64 - exact).abs() <= tolerance,
+                    "{kahan} != {exact}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f32_sum_kahan() {
+        // Every small item is lost by adding it to the large one, unless the errors are tracked.
+        let mut vec = vec![1e-8; 10_000];
+        vec[0] = 1.0;
+        assert_eq!(unsafe { sum_avx(&vec, SumMode::Kahan) }, 1.0001);
+
+        let vec = vec![0.1; 1_000_000];
+        assert_eq!(unsafe { sum_avx(&vec, SumMode::Kahan) }, 100_000.0);
+        assert_ne!(unsafe { sum_avx(&vec, SumMode::Fast) }, 100_000.0);
+
+        for special in [f32::INFINITY, f32::NEG_INFINITY, f32::NAN] {
+            let mut vec = vec![1.0; 100];
+            vec[50] = special;
+
+            for mode in [SumMode::Fast, SumMode::Kahan] {
+                assert_same_f32(Some(unsafe { sum_avx(&vec, mode) }), Some(special));
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f32_mismatch_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 7, 8, 31, 32, 33, 127, 128, 129, 300, 513] {
+            let vec: Vec<f32> = (0..size)
+                .map(|_| (rng.next_u32() % 1000) as f32 / 4.0)
+                .collect();
+
+            assert_eq!(unsafe { mismatch_avx(&vec, &vec) }, None);
+
+            for index in 0..size {
+                let mut other = vec.clone();
+                other[index] += 1.0;
+                assert_eq!(unsafe { mismatch_avx(&vec, &other) }, Some(index));
+                assert_eq!(unsafe { mismatch_avx(&other[..index], &vec) }, Some(index));
+                assert_eq!(unsafe { mismatch_avx(&vec, &vec[..index]) }, Some(index));
+
+                // NaN never equals itself.
+                let mut nan = vec.clone();
+                nan[index] = f32::NAN;
+                assert_eq!(unsafe { mismatch_avx(&nan, &nan) }, Some(index));
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f32_in_range_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 7, 31, 32, 33, 127, 128, 129, 300, 513] {
+            let vec = random_f32_with_specials(&mut rng, size, 10);
+
+            for _ in 0..20 {
+                let lo = rng.next_u32() as i32 as f32 / 102.0;
+                let hi = lo + (rng.next_u32() % (1 << 24)) as f32;
+
+                for (lo, hi) in [(lo, hi), (hi, lo), (f32::NEG_INFINITY, lo), (f32::NAN, hi)] {
+                    assert_eq!(
+                        unsafe { count_in_range_avx(&vec, lo, hi) },
+                        count_in_range_iter_f32(&vec, lo, hi),
+                        "{lo}..={hi}"
+                    );
+                    assert_eq!(
+                        unsafe { find_in_range_avx(&vec, lo, hi) },
+                        find_in_range_iter_f32(&vec, lo, hi),
+                        "{lo}..={hi}"
+                    );
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,432 @@
+use std::arch::x86_64::*;
+
+use crate::float::finish_min_max_f64;
+use crate::x86_64::array::i64_impl::{max_epi64, min_epi64};
+use crate::x86_64::simd_extensions::{
+    horizontal_max_i64_avx, horizontal_min_i64_avx, horizontal_sum_f64_avx, total_order_key_f64_avx,
+};
+
+/// Returns a mask with one bit per lane of `a`, set if `a` equals `b`. `NaN` is never equal to anything.
+#[inline]
+#[target_feature(enable = "avx")]
+fn eq_mask(a: __m256d, b: __m256d) -> u32 {
+    _mm256_movemask_pd(_mm256_cmp_pd::<_CMP_EQ_OQ>(a, b)) as u32
+}
+
+/// Returns `true` if `needle` is an element in the given array.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn contains_avx(array: &[f64], needle: f64) -> bool {
+    find_avx(array, needle).is_some()
+}
+
+/// Returns the position of `needle` in `array` if the array contains it.
+/// If there are multiple occurrences of `needle` in `array`, the first index gets returned.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_avx(array: &[f64], needle: f64) -> Option<usize> {
+    const STEP: usize = 16;
+
+    let needle_mask = _mm256_set1_pd(needle);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let compared = eq_mask(_mm256_loadu_pd(ptr), needle_mask);
+            let compared1 = eq_mask(_mm256_loadu_pd(ptr.add(4)), needle_mask);
+            let compared2 = eq_mask(_mm256_loadu_pd(ptr.add(8)), needle_mask);
+            let compared3 = eq_mask(_mm256_loadu_pd(ptr.add(12)), needle_mask);
+
+            let mask = compared | (compared1 << 4) | (compared2 << 8) | (compared3 << 12);
+            if mask != 0 {
+                return Some(i + mask.trailing_zeros() as usize);
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    array[vectorized_part..]
+        .iter()
+        .position(|i| *i == needle)
+        .map(|remainder_pos| remainder_pos + vectorized_part)
+}
+
+/// Returns the amount of occurrences of `needle` in `array`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn count_avx(array: &[f64], needle: f64) -> usize {
+    const STEP: usize = 16;
+
+    let needle_mask = _mm256_set1_pd(needle);
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut count = 0;
+
+    unsafe {
+        while i < iterr {
+            let cmp = eq_mask(_mm256_loadu_pd(ptr), needle_mask);
+            let cmp2 = eq_mask(_mm256_loadu_pd(ptr.add(4)), needle_mask);
+            let cmp3 = eq_mask(_mm256_loadu_pd(ptr.add(8)), needle_mask);
+            let cmp4 = eq_mask(_mm256_loadu_pd(ptr.add(12)), needle_mask);
+
+            count += (cmp | (cmp2 << 4) | (cmp3 << 8) | (cmp4 << 12)).count_ones() as usize;
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let remainder = array[iterr..].iter().filter(|i| **i == needle).count();
+    remainder + count
+}
+
+/// Returns the smallest item in the array, or `None` if the array was empty. NaN values are skipped as described by
+/// [`NanMode::Ignore`](crate::float::NanMode::Ignore).
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_avx(array: &[f64]) -> Option<f64> {
+    extreme_avx(array, false)
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty. NaN values are skipped as described by
+/// [`NanMode::Ignore`](crate::float::NanMode::Ignore).
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn max_avx(array: &[f64]) -> Option<f64> {
+    extreme_avx(array, true)
+}
+
+/// Returns the total order keys of `current`, with the keys of NaN lanes replaced by `nan_keys`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn keys_avx(current: __m256d, nan_keys: __m256i) -> __m256i {
+    let is_nan = _mm256_cmp_pd::<_CMP_UNORD_Q>(current, current);
+    _mm256_blendv_epi8(
+        total_order_key_f64_avx(current),
+        nan_keys,
+        _mm256_castpd_si256(is_nan),
+    )
+}
+
+/// Reduces the array to its minimum or maximum by comparing the total order keys of the items, which orders signed
+/// zeros independently of the operand order.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn extreme_avx(array: &[f64], max: bool) -> Option<f64> {
+    const STEP: usize = 16;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    // NaN items get the initial key, so they never replace another item.
+    let initial = _mm256_set1_epi64x(if max { i64::MIN } else { i64::MAX });
+    let mut lkeys1 = initial;
+    let mut lkeys2 = initial;
+    let mut lkeys3 = initial;
+    let mut lkeys4 = initial;
+
+    let extreme = |a, b| {
+        if max {
+            max_epi64(a, b)
+        } else {
+            min_epi64(a, b)
+        }
+    };
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_pd(ptr);
+            let current2 = _mm256_loadu_pd(ptr.add(4));
+            let current3 = _mm256_loadu_pd(ptr.add(8));
+            let current4 = _mm256_loadu_pd(ptr.add(12));
+
+            lkeys1 = extreme(lkeys1, keys_avx(current, initial));
+            lkeys2 = extreme(lkeys2, keys_avx(current2, initial));
+            lkeys3 = extreme(lkeys3, keys_avx(current3, initial));
+            lkeys4 = extreme(lkeys4, keys_avx(current4, initial));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let keys = extreme(extreme(lkeys1, lkeys2), extreme(lkeys3, lkeys4));
+    let key = if max {
+        horizontal_max_i64_avx(keys)
+    } else {
+        horizontal_min_i64_avx(keys)
+    };
+
+    finish_min_max_f64(array, iterr, key, max)
+}
+
+/// Returns the smallest and the largest item in the array, or `None` if the array was empty. Both are determined
+/// in a single pass, so the array is only loaded once. NaN values are skipped like by `min_avx` and `max_avx`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_max_avx(array: &[f64]) -> Option<(f64, f64)> {
+    const STEP: usize = 16;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let nan_min_keys = _mm256_set1_epi64x(i64::MAX);
+    let nan_max_keys = _mm256_set1_epi64x(i64::MIN);
+    let mut lmins1 = nan_min_keys;
+    let mut lmins2 = nan_min_keys;
+    let mut lmax1 = nan_max_keys;
+    let mut lmax2 = nan_max_keys;
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_pd(ptr);
+            let current2 = _mm256_loadu_pd(ptr.add(4));
+            let current3 = _mm256_loadu_pd(ptr.add(8));
+            let current4 = _mm256_loadu_pd(ptr.add(12));
+
+            lmins1 = min_epi64(lmins1, keys_avx(current, nan_min_keys));
+            lmins1 = min_epi64(lmins1, keys_avx(current2, nan_min_keys));
+            lmins2 = min_epi64(lmins2, keys_avx(current3, nan_min_keys));
+            lmins2 = min_epi64(lmins2, keys_avx(current4, nan_min_keys));
+            lmax1 = max_epi64(lmax1, keys_avx(current, nan_max_keys));
+            lmax1 = max_epi64(lmax1, keys_avx(current2, nan_max_keys));
+            lmax2 = max_epi64(lmax2, keys_avx(current3, nan_max_keys));
+            lmax2 = max_epi64(lmax2, keys_avx(current4, nan_max_keys));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let min_key = horizontal_min_i64_avx(min_epi64(lmins1, lmins2));
+    let max_key = horizontal_max_i64_avx(max_epi64(lmax1, lmax2));
+
+    finish_min_max_f64(array, iterr, min_key, false)
+        .zip(finish_min_max_f64(array, iterr, max_key, true))
+}
+
+/// Returns the sum of all items in the array, or `0.0` if the array was empty.
+///
+/// The items are added in a different order than by a sequential loop, so the result can differ from it by rounding.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn sum_avx(array: &[f64]) -> f64 {
+    const STEP: usize = 16;
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut sum1 = _mm256_setzero_pd();
+    let mut sum2 = _mm256_setzero_pd();
+    let mut sum3 = _mm256_setzero_pd();
+    let mut sum4 = _mm256_setzero_pd();
+
+    unsafe {
+        while i < iterr {
+            sum1 = _mm256_add_pd(sum1, _mm256_loadu_pd(ptr));
+            sum2 = _mm256_add_pd(sum2, _mm256_loadu_pd(ptr.add(4)));
+            sum3 = _mm256_add_pd(sum3, _mm256_loadu_pd(ptr.add(8)));
+            sum4 = _mm256_add_pd(sum4, _mm256_loadu_pd(ptr.add(12)));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let s1 = _mm256_add_pd(sum1, sum2);
+    let s2 = _mm256_add_pd(sum3, sum4);
+    let sum = horizontal_sum_f64_avx(_mm256_add_pd(s1, s2));
+
+    sum + array[iterr..].iter().sum::<f64>()
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+    use crate::original::array::{max_iter_f64, min_iter_f64, min_max_iter_f64, sum_iter_f64};
+
+    #[test]
+    fn test_array_f64_find_count_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 3, 4, 15, 16, 17, 31, 32, 33, 300, 513, 1030] {
+            for _ in 0..20 {
+                // Small value range to produce duplicates.
+                let vec: Vec<f64> = (0..len)
+                    .map(|_| (rng.next_u32() % 64) as f64 - 32.0)
+                    .collect();
+                let needle = (rng.next_u32() % 70) as f64 - 32.0;
+
+                let simd_find = unsafe { find_avx(&vec, needle) };
+                assert_eq!(simd_find, vec.iter().position(|i| *i == needle));
+
+                let simd_contains = unsafe { contains_avx(&vec, needle) };
+                assert_eq!(simd_contains, vec.contains(&needle));
+
+                let simd_count = unsafe { count_avx(&vec, needle) };
+                assert_eq!(simd_count, vec.iter().filter(|i| **i == needle).count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f64_find_special_values() {
+        let mut vec = vec![1.0; 100];
+        vec[40] = -0.0;
+        vec[70] = f64::NAN;
+
+        // Signed zeros are equal, NaN is never found.
+        unsafe {
+            assert_eq!(find_avx(&vec, 0.0), Some(40));
+            assert_eq!(count_avx(&vec, 0.0), 1);
+            assert_eq!(find_avx(&vec, f64::NAN), None);
+            assert!(!contains_avx(&vec, f64::NAN));
+            assert_eq!(count_avx(&vec, f64::NAN), 0);
+        }
+    }
+
+    #[test]
+    fn test_array_f64_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in (0..5000).step_by(13) {
+            for _ in 0..5 {
+                let vec: Vec<f64> = (0..len)
+                    .map(|_| rng.next_u64() as f64 / 1e6 - 9e12)
+                    .collect();
+
+                let simd_min = unsafe { min_avx(&vec) };
+                assert_eq!(simd_min, min_iter_f64(&vec));
+
+                let simd_max = unsafe { max_avx(&vec) };
+                assert_eq!(simd_max, max_iter_f64(&vec));
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f64_min_max_special_values() {
+        const SPECIALS: [f64; 6] = [
+            f64::NAN,
+            -f64::NAN,
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let bits = |value: Option<f64>| value.map(f64::to_bits);
+
+        for len in [0, 1, 15, 16, 17, 31, 40, 64, 300, 1030] {
+            for special_rate in [1, 2, 8, 64] {
+                let vec: Vec<f64> = (0..len)
+                    .map(|_| match rng.next_u32() % special_rate {
+                        0 => SPECIALS[rng.next_u32() as usize % SPECIALS.len()],
+                        _ => (rng.next_u32() % 64) as f64 - 32.0,
+                    })
+                    .collect();
+
+                assert_eq!(
+                    bits(unsafe { min_avx(&vec) }),
+                    bits(min_iter_f64(&vec)),
+                    "{vec:?}"
+                );
+                assert_eq!(
+                    bits(unsafe { max_avx(&vec) }),
+                    bits(max_iter_f64(&vec)),
+                    "{vec:?}"
+                );
+
+                let expected =
+                    min_max_iter_f64(&vec).map(|(min, max)| (min.to_bits(), max.to_bits()));
+                let min_max = unsafe { min_max_avx(&vec) };
+                assert_eq!(
+                    min_max.map(|(min, max)| (min.to_bits(), max.to_bits())),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f64_min_max_single_pass_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in (0..5000).step_by(13) {
+            let vec: Vec<f64> = (0..len)
+                .map(|_| rng.next_u64() as f64 / 1e6 - 9e12)
+                .collect();
+
+            let expected = min_iter_f64(&vec).zip(max_iter_f64(&vec));
+            assert_eq!(unsafe { min_max_avx(&vec) }, expected);
+        }
+    }
+
+    #[test]
+    fn test_array_f64_sum_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in (0..5000).step_by(13) {
+            // Integers are added without rounding, so the order doesn't matter.
+            let vec: Vec<f64> = (0..len)
+                .map(|_| (rng.next_u32() % 2000) as f64 - 1000.0)
+                .collect();
+            assert_eq!(unsafe { sum_avx(&vec) }, sum_iter_f64(&vec));
+
+            let vec: Vec<f64> = (0..len).map(|_| rng.next_u32() as f64 / 7.0).collect();
+            let expected = sum_iter_f64(&vec);
+            let sum = unsafe { sum_avx(&vec) };
+            assert!((sum - expected).abs() <= expected.abs() * 1e-12);
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use std::arch::x86_64::*;
+
+use crate::x86_64::simd_extensions::{horizontal_max_i16_avx, horizontal_min_i16_avx};
+
+/// Returns `true` if `needle` is an element in the given array.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn contains_avx(array: &[i16], needle: i16) -> bool {
+    const STEP: usize = 64;
+
+    let needle_mask = _mm256_set1_epi16(needle);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm256_loadu_si256(ptr.cast());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(16).cast());
+            let curr_items_p2 = _mm256_loadu_si256(ptr.add(32).cast());
+            let curr_items_p3 = _mm256_loadu_si256(ptr.add(48).cast());
+
+            let compared = _mm256_cmpeq_epi16(needle_mask, curr_items);
+            let compared1 = _mm256_cmpeq_epi16(needle_mask, curr_items_p1);
+            let compared2 = _mm256_cmpeq_epi16(needle_mask, curr_items_p2);
+            let compared3 = _mm256_cmpeq_epi16(needle_mask, curr_items_p3);
+
+            let any = _mm256_or_si256(
+                _mm256_or_si256(compared, compared1),
+                _mm256_or_si256(compared2, compared3),
+            );
+
+            if _mm256_testz_si256(any, any) == 0 {
+                return true;
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    array[vectorized_part..].contains(&needle)
+}
+
+/// Returns the position of `needle` in `array` if the array contains it.
+/// If there are multiple occurrences of `needle` in `array`, the first index gets returned.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_avx(array: &[i16], needle: i16) -> Option<usize> {
+    const STEP: usize = 64;
+
+    let needle_mask = _mm256_set1_epi16(needle);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm256_loadu_si256(ptr.cast());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(16).cast());
+            let curr_items_p2 = _mm256_loadu_si256(ptr.add(32).cast());
+            let curr_items_p3 = _mm256_loadu_si256(ptr.add(48).cast());
+
+            // Each element occupies 2 bits of the byte masks.
+            let compared = _mm256_movemask_epi8(_mm256_cmpeq_epi16(needle_mask, curr_items)) as u32;
+            let compared1 =
+                _mm256_movemask_epi8(_mm256_cmpeq_epi16(needle_mask, curr_items_p1)) as u32;
+            let compared2 =
+                _mm256_movemask_epi8(_mm256_cmpeq_epi16(needle_mask, curr_items_p2)) as u32;
+            let compared3 =
+                _mm256_movemask_epi8(_mm256_cmpeq_epi16(needle_mask, curr_items_p3)) as u32;
+
+            if (compared | compared1 | compared2 | compared3) != 0 {
+                if compared != 0 {
+                    return Some(i + compared.trailing_zeros() as usize / 2);
+                }
+
+                if compared1 != 0 {
+                    return Some(i + 16 + compared1.trailing_zeros() as usize / 2);
+                }
+
+                if compared2 != 0 {
+                    return Some(i + 32 + compared2.trailing_zeros() as usize / 2);
+                }
+
+                return Some(i + 48 + compared3.trailing_zeros() as usize / 2);
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    array[vectorized_part..]
+        .iter()
+        .position(|i| *i == needle)
+        .map(|remainder_pos| remainder_pos + vectorized_part)
+}
+
+/// Returns the amount of occurrences of `needle` in `array`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn count_avx(array: &[i16], needle: i16) -> usize {
+    const STEP: usize = 64;
+
+    let needle_mask = _mm256_set1_epi16(needle);
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut count = 0;
+
+    unsafe {
+        while i < iterr {
+            let curr_items = _mm256_loadu_si256(ptr.cast());
+            let curr_items_2 = _mm256_loadu_si256(ptr.add(16).cast());
+            let curr_items_3 = _mm256_loadu_si256(ptr.add(32).cast());
+            let curr_items_4 = _mm256_loadu_si256(ptr.add(48).cast());
+
+            let cmp = _mm256_cmpeq_epi16(curr_items, needle_mask);
+            let cmp2 = _mm256_cmpeq_epi16(curr_items_2, needle_mask);
+            let cmp3 = _mm256_cmpeq_epi16(curr_items_3, needle_mask);
+            let cmp4 = _mm256_cmpeq_epi16(curr_items_4, needle_mask);
+
+            // Narrow the 16 bit lanes (0 or -1) to bytes, so each match sets exactly one bit of the byte masks.
+            // The packing reorders the lanes, which doesn't matter for counting.
+            let packed = _mm256_movemask_epi8(_mm256_packs_epi16(cmp, cmp2)) as u32;
+            let packed2 = _mm256_movemask_epi8(_mm256_packs_epi16(cmp3, cmp4)) as u32;
+
+            count += (packed.count_ones() + packed2.count_ones()) as usize;
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let remainder = array[iterr..].iter().filter(|i| **i == needle).count();
+    remainder + count
+}
+
+/// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_avx(array: &[i16]) -> Option<i16> {
+    const STEP: usize = 64;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm256_set1_epi16(i16::MAX);
+    let mut lmins2 = _mm256_set1_epi16(i16::MAX);
+    let mut lmins3 = _mm256_set1_epi16(i16::MAX);
+    let mut lmins4 = _mm256_set1_epi16(i16::MAX);
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(16).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(32).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(48).cast());
+
+            lmins1 = _mm256_min_epi16(current, lmins1);
+            lmins2 = _mm256_min_epi16(current2, lmins2);
+            lmins3 = _mm256_min_epi16(current3, lmins3);
+            lmins4 = _mm256_min_epi16(current4, lmins4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = _mm256_min_epi16(lmins1, lmins2);
+    let m2 = _mm256_min_epi16(lmins3, lmins4);
+    let min = horizontal_min_i16_avx(_mm256_min_epi16(m1, m2));
+
+    if let Some(remainer_min) = array[iterr..].iter().min() {
+        return Some(min.min(*remainer_min));
+    }
+
+    Some(min)
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn max_avx(array: &[i16]) -> Option<i16> {
+    const STEP: usize = 64;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmax1 = _mm256_set1_epi16(i16::MIN);
+    let mut lmax2 = _mm256_set1_epi16(i16::MIN);
+    let mut lmax3 = _mm256_set1_epi16(i16::MIN);
+    let mut lmax4 = _mm256_set1_epi16(i16::MIN);
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(16).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(32).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(48).cast());
+
+            lmax1 = _mm256_max_epi16(current, lmax1);
+            lmax2 = _mm256_max_epi16(current2, lmax2);
+            lmax3 = _mm256_max_epi16(current3, lmax3);
+            lmax4 = _mm256_max_epi16(current4, lmax4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = _mm256_max_epi16(lmax1, lmax2);
+    let m2 = _mm256_max_epi16(lmax3, lmax4);
+    let max = horizontal_max_i16_avx(_mm256_max_epi16(m1, m2));
+
+    if let Some(remainer_max) = array[iterr..].iter().max() {
+        return Some(max.max(*remainer_max));
+    }
+
+    Some(max)
+}
+
+/// Returns the smallest and the largest item in the array, or `None` if the array was empty. Both are determined
+/// in a single pass, so the array is only loaded once.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_max_avx(array: &[i16]) -> Option<(i16, i16)> {
+    const STEP: usize = 64;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm256_set1_epi16(i16::MAX);
+    let mut lmins2 = lmins1;
+    let mut lmax1 = _mm256_set1_epi16(i16::MIN);
+    let mut lmax2 = lmax1;
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(16).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(32).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(48).cast());
+
+            lmins1 = _mm256_min_epi16(lmins1, _mm256_min_epi16(current, current2));
+            lmins2 = _mm256_min_epi16(lmins2, _mm256_min_epi16(current3, current4));
+            lmax1 = _mm256_max_epi16(lmax1, _mm256_max_epi16(current, current2));
+            lmax2 = _mm256_max_epi16(lmax2, _mm256_max_epi16(current3, current4));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let mut min = horizontal_min_i16_avx(_mm256_min_epi16(lmins1, lmins2));
+    let mut max = horizontal_max_i16_avx(_mm256_max_epi16(lmax1, lmax2));
+
+    for item in &array[iterr..] {
+        min = min.min(*item);
+        max = max.max(*item);
+    }
+
+    Some((min, max))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_array_find_count_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 15, 16, 63, 64, 65, 127, 128, 300, 513, 1030] {
+            for _ in 0..20 {
+                // Small value range around zero to produce duplicates of both signs.
+                let vec: Vec<i16> = (0..len)
+                    .map(|_| (rng.next_u32() % 64) as i16 - 32)
+                    .collect();
+                let needle = (rng.next_u32() % 70) as i16 - 35;
+
+                let simd_find = unsafe { find_avx(&vec, needle) };
+                assert_eq!(simd_find, vec.iter().position(|i| *i == needle));
+
+                let simd_contains = unsafe { contains_avx(&vec, needle) };
+                assert_eq!(simd_contains, vec.contains(&needle));
+
+                let simd_count = unsafe { count_avx(&vec, needle) };
+                assert_eq!(simd_count, vec.iter().filter(|i| **i == needle).count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 16, 63, 64, 65, 128, 256, 513, 1024, 6256] {
+            let vec: Vec<i16> = (0..len).map(|_| rng.next_u32() as i16).collect();
+
+            let simd_min = unsafe { min_avx(&vec) };
+            assert_eq!(simd_min, vec.iter().min().copied());
+
+            let simd_max = unsafe { max_avx(&vec) };
+            assert_eq!(simd_max, vec.iter().max().copied());
+        }
+
+        // All values being either negative or positive.
+        for vec in [
+            vec![-3i16; 200],
+            vec![5i16; 200],
+            vec![i16::MIN; 200],
+            vec![i16::MAX; 200],
+        ] {
+            assert_eq!(unsafe { min_avx(&vec) }, vec.iter().min().copied());
+            assert_eq!(unsafe { max_avx(&vec) }, vec.iter().max().copied());
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_single_pass_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 63, 64, 65, 128, 513, 1024, 6256] {
+            let vec: Vec<i16> = (0..len).map(|_| rng.next_u32() as i16).collect();
+
+            let expected = vec.iter().min().copied().zip(vec.iter().max().copied());
+            assert_eq!(unsafe { min_max_avx(&vec) }, expected);
+        }
+
+        for vec in [vec![i16::MIN; 200], vec![i16::MAX; 200]] {
+            assert_eq!(unsafe { min_max_avx(&vec) }, Some((vec[0], vec[0])));
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use std::arch::x86_64::*;
+
+use crate::x86_64::simd_extensions::{horizontal_max_i64_avx, horizontal_min_i64_avx};
+
+/// Returns a mask with one bit per 64 bit lane, set if all bits of the lane are set.
+#[inline]
+#[target_feature(enable = "avx")]
+fn lanes_mask(a: __m256i) -> u32 {
+    _mm256_movemask_pd(_mm256_castsi256_pd(a)) as u32
+}
+
+/// Returns the element wise minimum of `a` and `b`, which AVX2 doesn't provide for 64 bit integers.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(crate) fn min_epi64(a: __m256i, b: __m256i) -> __m256i {
+    _mm256_blendv_epi8(a, b, _mm256_cmpgt_epi64(a, b))
+}
+
+/// Returns the element wise maximum of `a` and `b`, which AVX2 doesn't provide for 64 bit integers.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(crate) fn max_epi64(a: __m256i, b: __m256i) -> __m256i {
+    _mm256_blendv_epi8(a, b, _mm256_cmpgt_epi64(b, a))
+}
+
+/// Returns `true` if `needle` is an element in the given array.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn contains_avx(array: &[i64], needle: i64) -> bool {
+    const STEP: usize = 16;
+
+    let needle_mask = _mm256_set1_epi64x(needle);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm256_loadu_si256(ptr.cast());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(4).cast());
+            let curr_items_p2 = _mm256_loadu_si256(ptr.add(8).cast());
+            let curr_items_p3 = _mm256_loadu_si256(ptr.add(12).cast());
+
+            let compared = _mm256_cmpeq_epi64(needle_mask, curr_items);
+            let compared1 = _mm256_cmpeq_epi64(needle_mask, curr_items_p1);
+            let compared2 = _mm256_cmpeq_epi64(needle_mask, curr_items_p2);
+            let compared3 = _mm256_cmpeq_epi64(needle_mask, curr_items_p3);
+
+            let any = _mm256_or_si256(
+                _mm256_or_si256(compared, compared1),
+                _mm256_or_si256(compared2, compared3),
+            );
+
+            if _mm256_testz_si256(any, any) == 0 {
+                return true;
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    array[vectorized_part..].contains(&needle)
+}
+
+/// Returns the position of `needle` in `array` if the array contains it.
+/// If there are multiple occurrences of `needle` in `array`, the first index gets returned.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_avx(array: &[i64], needle: i64) -> Option<usize> {
+    const STEP: usize = 16;
+
+    let needle_mask = _mm256_set1_epi64x(needle);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm256_loadu_si256(ptr.cast());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(4).cast());
+            let curr_items_p2 = _mm256_loadu_si256(ptr.add(8).cast());
+            let curr_items_p3 = _mm256_loadu_si256(ptr.add(12).cast());
+
+            let compared = lanes_mask(_mm256_cmpeq_epi64(needle_mask, curr_items));
+            let compared1 = lanes_mask(_mm256_cmpeq_epi64(needle_mask, curr_items_p1));
+            let compared2 = lanes_mask(_mm256_cmpeq_epi64(needle_mask, curr_items_p2));
+            let compared3 = lanes_mask(_mm256_cmpeq_epi64(needle_mask, curr_items_p3));
+
+            let mask = compared | (compared1 << 4) | (compared2 << 8) | (compared3 << 12);
+            if mask != 0 {
+                return Some(i + mask.trailing_zeros() as usize);
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    array[vectorized_part..]
+        .iter()
+        .position(|i| *i == needle)
+        .map(|remainder_pos| remainder_pos + vectorized_part)
+}
+
+/// Returns the amount of occurrences of `needle` in `array`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn count_avx(array: &[i64], needle: i64) -> usize {
+    const STEP: usize = 16;
+
+    let needle_mask = _mm256_set1_epi64x(needle);
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut count = 0;
+
+    unsafe {
+        while i < iterr {
+            let curr_items = _mm256_loadu_si256(ptr.cast());
+            let curr_items_2 = _mm256_loadu_si256(ptr.add(4).cast());
+            let curr_items_3 = _mm256_loadu_si256(ptr.add(8).cast());
+            let curr_items_4 = _mm256_loadu_si256(ptr.add(12).cast());
+
+            let cmp = lanes_mask(_mm256_cmpeq_epi64(curr_items, needle_mask));
+            let cmp2 = lanes_mask(_mm256_cmpeq_epi64(curr_items_2, needle_mask));
+            let cmp3 = lanes_mask(_mm256_cmpeq_epi64(curr_items_3, needle_mask));
+            let cmp4 = lanes_mask(_mm256_cmpeq_epi64(curr_items_4, needle_mask));
+
+            count += (cmp | (cmp2 << 4) | (cmp3 << 8) | (cmp4 << 12)).count_ones() as usize;
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let remainder = array[iterr..].iter().filter(|i| **i == needle).count();
+    remainder + count
+}
+
+/// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_avx(array: &[i64]) -> Option<i64> {
+    const STEP: usize = 16;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm256_set1_epi64x(i64::MAX);
+    let mut lmins2 = _mm256_set1_epi64x(i64::MAX);
+    let mut lmins3 = _mm256_set1_epi64x(i64::MAX);
+    let mut lmins4 = _mm256_set1_epi64x(i64::MAX);
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(4).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(8).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(12).cast());
+
+            lmins1 = min_epi64(lmins1, current);
+            lmins2 = min_epi64(lmins2, current2);
+            lmins3 = min_epi64(lmins3, current3);
+            lmins4 = min_epi64(lmins4, current4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = min_epi64(lmins1, lmins2);
+    let m2 = min_epi64(lmins3, lmins4);
+    let min = horizontal_min_i64_avx(min_epi64(m1, m2));
+
+    if let Some(remainer_min) = array[iterr..].iter().min() {
+        return Some(min.min(*remainer_min));
+    }
+
+    Some(min)
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn max_avx(array: &[i64]) -> Option<i64> {
+    const STEP: usize = 16;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmax1 = _mm256_set1_epi64x(i64::MIN);
+    let mut lmax2 = _mm256_set1_epi64x(i64::MIN);
+    let mut lmax3 = _mm256_set1_epi64x(i64::MIN);
+    let mut lmax4 = _mm256_set1_epi64x(i64::MIN);
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(4).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(8).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(12).cast());
+
+            lmax1 = max_epi64(lmax1, current);
+            lmax2 = max_epi64(lmax2, current2);
+            lmax3 = max_epi64(lmax3, current3);
+            lmax4 = max_epi64(lmax4, current4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = max_epi64(lmax1, lmax2);
+    let m2 = max_epi64(lmax3, lmax4);
+    let max = horizontal_max_i64_avx(max_epi64(m1, m2));
+
+    if let Some(remainer_max) = array[iterr..].iter().max() {
+        return Some(max.max(*remainer_max));
+    }
+
+    Some(max)
+}
+
+/// Returns the smallest and the largest item in the array, or `None` if the array was empty. Both are determined
+/// in a single pass, so the array is only loaded once.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_max_avx(array: &[i64]) -> Option<(i64, i64)> {
+    const STEP: usize = 16;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm256_set1_epi64x(i64::MAX);
+    let mut lmins2 = lmins1;
+    let mut lmax1 = _mm256_set1_epi64x(i64::MIN);
+    let mut lmax2 = lmax1;
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(4).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(8).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(12).cast());
+
+            lmins1 = min_epi64(lmins1, min_epi64(current, current2));
+            lmins2 = min_epi64(lmins2, min_epi64(current3, current4));
+            lmax1 = max_epi64(lmax1, max_epi64(current, current2));
+            lmax2 = max_epi64(lmax2, max_epi64(current3, current4));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let mut min = horizontal_min_i64_avx(min_epi64(lmins1, lmins2));
+    let mut max = horizontal_max_i64_avx(max_epi64(lmax1, lmax2));
+
+    for item in &array[iterr..] {
+        min = min.min(*item);
+        max = max.max(*item);
+    }
+
+    Some((min, max))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_array_find_count_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 3, 4, 15, 16, 17, 31, 32, 33, 300, 513, 1030] {
+            for _ in 0..20 {
+                // Small value range around zero to produce duplicates of both signs.
+                let vec: Vec<i64> = (0..len)
+                    .map(|_| (rng.next_u32() % 64) as i64 - 32)
+                    .collect();
+                let needle = (rng.next_u32() % 70) as i64 - 35;
+
+                let simd_find = unsafe { find_avx(&vec, needle) };
+                assert_eq!(simd_find, vec.iter().position(|i| *i == needle));
+
+                let simd_contains = unsafe { contains_avx(&vec, needle) };
+                assert_eq!(simd_contains, vec.contains(&needle));
+
+                let simd_count = unsafe { count_avx(&vec, needle) };
+                assert_eq!(simd_count, vec.iter().filter(|i| **i == needle).count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 4, 15, 16, 17, 64, 127, 128, 513, 1024, 6256] {
+            let vec: Vec<i64> = (0..len).map(|_| rng.next_u64() as i64).collect();
+
+            let simd_min = unsafe { min_avx(&vec) };
+            assert_eq!(simd_min, vec.iter().min().copied());
+
+            let simd_max = unsafe { max_avx(&vec) };
+            assert_eq!(simd_max, vec.iter().max().copied());
+        }
+
+        // All values being either negative or positive.
+        for vec in [
+            vec![-3i64; 200],
+            vec![5i64; 200],
+            vec![i64::MIN; 200],
+            vec![i64::MAX; 200],
+        ] {
+            assert_eq!(unsafe { min_avx(&vec) }, vec.iter().min().copied());
+            assert_eq!(unsafe { max_avx(&vec) }, vec.iter().max().copied());
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_single_pass_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 15, 16, 17, 64, 127, 513, 1024, 6256] {
+            let vec: Vec<i64> = (0..len).map(|_| rng.next_u64() as i64).collect();
+
+            let expected = vec.iter().min().copied().zip(vec.iter().max().copied());
+            assert_eq!(unsafe { min_max_avx(&vec) }, expected);
+        }
+
+        for vec in [vec![i64::MIN; 200], vec![i64::MAX; 200]] {
+            assert_eq!(unsafe { min_max_avx(&vec) }, Some((vec[0], vec[0])));
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use std::arch::x86_64::*;
+use std::slice;
+
+use crate::x86_64::array::u8_impl;
+use crate::x86_64::simd_extensions::{horizontal_max_i8_avx, horizontal_min_i8_avx};
+
+/// Reinterprets the array as unsigned integers, as equality doesn't depend on the sign.
+#[inline]
+fn as_unsigned(array: &[i8]) -> &[u8] {
+    // Safety: i8 and u8 have the same size and alignment, and every bit pattern is valid for both.
+    unsafe { slice::from_raw_parts(array.as_ptr().cast(), array.len()) }
+}
+
+/// Returns `true` if `needle` is an element in the given array.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn contains_avx(array: &[i8], needle: i8) -> bool {
+    u8_impl::contains_avx(as_unsigned(array), needle as u8)
+}
+
+/// Returns the position of `needle` in `array` if the array contains it.
+/// If there are multiple occurrences of `needle` in `array`, the first index gets returned.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_avx(array: &[i8], needle: i8) -> Option<usize> {
+    u8_impl::find_avx(as_unsigned(array), needle as u8)
+}
+
+/// Returns the amount of occurrences of `needle` in `array`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn count_avx(array: &[i8], needle: i8) -> usize {
+    u8_impl::count_avx(as_unsigned(array), needle as u8)
+}
+
+/// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_avx(array: &[i8]) -> Option<i8> {
+    const STEP: usize = 128;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm256_set1_epi8(i8::MAX);
+    let mut lmins2 = _mm256_set1_epi8(i8::MAX);
+    let mut lmins3 = _mm256_set1_epi8(i8::MAX);
+    let mut lmins4 = _mm256_set1_epi8(i8::MAX);
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(32).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(64).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(96).cast());
+
+            lmins1 = _mm256_min_epi8(current, lmins1);
+            lmins2 = _mm256_min_epi8(current2, lmins2);
+            lmins3 = _mm256_min_epi8(current3, lmins3);
+            lmins4 = _mm256_min_epi8(current4, lmins4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = _mm256_min_epi8(lmins1, lmins2);
+    let m2 = _mm256_min_epi8(lmins3, lmins4);
+    let min = horizontal_min_i8_avx(_mm256_min_epi8(m1, m2));
+
+    if let Some(remainer_min) = array[iterr..].iter().min() {
+        return Some(min.min(*remainer_min));
+    }
+
+    Some(min)
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn max_avx(array: &[i8]) -> Option<i8> {
+    const STEP: usize = 128;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmax1 = _mm256_set1_epi8(i8::MIN);
+    let mut lmax2 = _mm256_set1_epi8(i8::MIN);
+    let mut lmax3 = _mm256_set1_epi8(i8::MIN);
+    let mut lmax4 = _mm256_set1_epi8(i8::MIN);
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(32).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(64).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(96).cast());
+
+            lmax1 = _mm256_max_epi8(current, lmax1);
+            lmax2 = _mm256_max_epi8(current2, lmax2);
+            lmax3 = _mm256_max_epi8(current3, lmax3);
+            lmax4 = _mm256_max_epi8(current4, lmax4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = _mm256_max_epi8(lmax1, lmax2);
+    let m2 = _mm256_max_epi8(lmax3, lmax4);
+    let max = horizontal_max_i8_avx(_mm256_max_epi8(m1, m2));
+
+    if let Some(remainer_max) = array[iterr..].iter().max() {
+        return Some(max.max(*remainer_max));
+    }
+
+    Some(max)
+}
+
+/// Returns the smallest and the largest item in the array, or `None` if the array was empty. Both are determined
+/// in a single pass, so the array is only loaded once.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_max_avx(array: &[i8]) -> Option<(i8, i8)> {
+    const STEP: usize = 128;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm256_set1_epi8(i8::MAX);
+    let mut lmins2 = lmins1;
+    let mut lmax1 = _mm256_set1_epi8(i8::MIN);
+    let mut lmax2 = lmax1;
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(32).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(64).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(96).cast());
+
+            lmins1 = _mm256_min_epi8(lmins1, _mm256_min_epi8(current, current2));
+            lmins2 = _mm256_min_epi8(lmins2, _mm256_min_epi8(current3, current4));
+            lmax1 = _mm256_max_epi8(lmax1, _mm256_max_epi8(current, current2));
+            lmax2 = _mm256_max_epi8(lmax2, _mm256_max_epi8(current3, current4));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let mut min = horizontal_min_i8_avx(_mm256_min_epi8(lmins1, lmins2));
+    let mut max = horizontal_max_i8_avx(_mm256_max_epi8(lmax1, lmax2));
+
+    for item in &array[iterr..] {
+        min = min.min(*item);
+        max = max.max(*item);
+    }
+
+    Some((min, max))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_array_find_count_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 31, 32, 127, 128, 129, 300, 513, 1030] {
+            for _ in 0..20 {
+                // Small value range around zero to produce duplicates of both signs.
+                let vec: Vec<i8> = (0..len).map(|_| (rng.next_u32() % 16) as i8 - 8).collect();
+                let needle = (rng.next_u32() % 20) as i8 - 10;
+
+                let simd_find = unsafe { find_avx(&vec, needle) };
+                assert_eq!(simd_find, vec.iter().position(|i| *i == needle));
+
+                let simd_contains = unsafe { contains_avx(&vec, needle) };
+                assert_eq!(simd_contains, vec.contains(&needle));
+
+                let simd_count = unsafe { count_avx(&vec, needle) };
+                assert_eq!(simd_count, vec.iter().filter(|i| **i == needle).count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 32, 127, 128, 129, 256, 513, 1024, 6256] {
+            let vec: Vec<i8> = (0..len).map(|_| rng.next_u32() as i8).collect();
+
+            let simd_min = unsafe { min_avx(&vec) };
+            assert_eq!(simd_min, vec.iter().min().copied());
+
+            let simd_max = unsafe { max_avx(&vec) };
+            assert_eq!(simd_max, vec.iter().max().copied());
+        }
+
+        // All values being either negative or positive.
+        for vec in [
+            vec![-3i8; 200],
+            vec![5i8; 200],
+            vec![i8::MIN; 200],
+            vec![i8::MAX; 200],
+        ] {
+            assert_eq!(unsafe { min_avx(&vec) }, vec.iter().min().copied());
+            assert_eq!(unsafe { max_avx(&vec) }, vec.iter().max().copied());
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_single_pass_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 127, 128, 129, 256, 513, 1024, 6256] {
+            let vec: Vec<i8> = (0..len).map(|_| rng.next_u32() as i8).collect();
+
+            let expected = vec.iter().min().copied().zip(vec.iter().max().copied());
+            assert_eq!(unsafe { min_max_avx(&vec) }, expected);
+        }
+
+        for vec in [vec![i8::MIN; 200], vec![i8::MAX; 200]] {
+            assert_eq!(unsafe { min_max_avx(&vec) }, Some((vec[0], vec[0])));
+        }
+    }
+}
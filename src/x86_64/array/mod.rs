@@ -1,3 +1,12 @@
+pub mod avx512;
 pub mod f32_impl;
+pub mod f64_impl;
+pub mod i16_impl;
+pub mod i32_impl;
+pub mod i64_impl;
+pub mod i8_impl;
+pub mod sse;
+pub mod u16_impl;
 pub mod u32_impl;
+pub mod u64_impl;
 pub mod u8_impl;
@@ -0,0 +1,160 @@
+use std::arch::x86_64::*;
+
+use crate::float::{NanMode, finish_min_max_f32};
+use crate::x86_64::simd_extensions::{
+    horizontal_max_i32_sse, horizontal_min_i32_sse, total_order_key_f32_sse,
+};
+
+/// Returns the smallest item in the array, or `None` if the array was empty. NaN values are handled as described
+/// by `mode`.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub fn min_sse(array: &[f32], mode: NanMode) -> Option<f32> {
+    // Passing constants lets the compiler remove the unused branches of `min_max_sse`.
+    match mode {
+        NanMode::Ignore => min_max_sse(array, false, NanMode::Ignore),
+        NanMode::Propagate => min_max_sse(array, false, NanMode::Propagate),
+        NanMode::TotalOrder => min_max_sse(array, false, NanMode::TotalOrder),
+    }
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty. NaN values are handled as described
+/// by `mode`.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub fn max_sse(array: &[f32], mode: NanMode) -> Option<f32> {
+    match mode {
+        NanMode::Ignore => min_max_sse(array, true, NanMode::Ignore),
+        NanMode::Propagate => min_max_sse(array, true, NanMode::Propagate),
+        NanMode::TotalOrder => min_max_sse(array, true, NanMode::TotalOrder),
+    }
+}
+
+/// Reduces the array to its minimum or maximum by comparing the total order keys of the items, which orders signed
+/// zeros and NaNs independently of the operand order.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+fn min_max_sse(array: &[f32], max: bool, mode: NanMode) -> Option<f32> {
+    const STEP: usize = 16;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lkeys1 = _mm_set1_epi32(if max { i32::MIN } else { i32::MAX });
+    let mut lkeys2 = lkeys1;
+    let mut lkeys3 = lkeys1;
+    let mut lkeys4 = lkeys1;
+    let mut nans = _mm_setzero_ps();
+
+    unsafe {
+        while i < iterr {
+            let current = _mm_loadu_ps(ptr);
+            let current2 = _mm_loadu_ps(ptr.add(4));
+            let current3 = _mm_loadu_ps(ptr.add(8));
+            let current4 = _mm_loadu_ps(ptr.add(12));
+
+            lkeys1 = accumulate(lkeys1, &mut nans, current, max, mode);
+            lkeys2 = accumulate(lkeys2, &mut nans, current2, max, mode);
+            lkeys3 = accumulate(lkeys3, &mut nans, current3, max, mode);
+            lkeys4 = accumulate(lkeys4, &mut nans, current4, max, mode);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let key = if max {
+        let m1 = _mm_max_epi32(lkeys1, lkeys2);
+        let m2 = _mm_max_epi32(lkeys3, lkeys4);
+        horizontal_max_i32_sse(_mm_max_epi32(m1, m2))
+    } else {
+        let m1 = _mm_min_epi32(lkeys1, lkeys2);
+        let m2 = _mm_min_epi32(lkeys3, lkeys4);
+        horizontal_min_i32_sse(_mm_min_epi32(m1, m2))
+    };
+
+    let nan_seen = _mm_movemask_ps(nans) != 0;
+    finish_min_max_f32(array, iterr, key, nan_seen, max, mode)
+}
+
+/// Merges the total order keys of `current` into `keys`, and records its NaN lanes in `nans`.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+fn accumulate(
+    keys: __m128i,
+    nans: &mut __m128,
+    current: __m128,
+    max: bool,
+    mode: NanMode,
+) -> __m128i {
+    let mut current_keys = total_order_key_f32_sse(current);
+
+    if mode != NanMode::TotalOrder {
+        let is_nan = _mm_cmpunord_ps(current, current);
+        *nans = _mm_or_ps(*nans, is_nan);
+
+        if mode == NanMode::Ignore {
+            current_keys = _mm_blendv_epi8(current_keys, keys, _mm_castps_si128(is_nan));
+        }
+    }
+
+    if max {
+        _mm_max_epi32(keys, current_keys)
+    } else {
+        _mm_min_epi32(keys, current_keys)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+    use crate::float::test::{NAN_MODES, assert_same_f32, random_f32_with_specials};
+    use crate::original::array::{max_iter_f32, min_iter_f32};
+
+    #[test]
+    fn test_array_f32_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in (16..5000).step_by(13) {
+            for _ in 0..10 {
+                let vec: Vec<_> = (0..len)
+                    .map(|_| rng.next_u32() as i32 as f32 / 102.0)
+                    .collect();
+
+                let simd_min = unsafe { min_sse(&vec, NanMode::Ignore) };
+                assert_eq!(simd_min, min_iter_f32(&vec, NanMode::Ignore));
+
+                let simd_max = unsafe { max_sse(&vec, NanMode::Ignore) };
+                assert_eq!(simd_max, max_iter_f32(&vec, NanMode::Ignore));
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_f32_min_max_special_values_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 2, 7, 15, 16, 17, 64, 100, 513, 1030] {
+            for special_rate in [1, 2, 16, 1000] {
+                let vec = random_f32_with_specials(&mut rng, len, special_rate);
+
+                for mode in NAN_MODES {
+                    assert_same_f32(unsafe { min_sse(&vec, mode) }, min_iter_f32(&vec, mode));
+                    assert_same_f32(unsafe { max_sse(&vec, mode) }, max_iter_f32(&vec, mode));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod f32_impl;
+pub mod u32_impl;
+pub mod u8_impl;
@@ -0,0 +1,282 @@
+use std::arch::x86_64::*;
+
+use crate::x86_64::simd_extensions::{
+    horizontal_max_u32_sse, horizontal_min_u32_sse, horizontal_sum_u32_sse,
+};
+
+/// Returns `true` if `needle` is an element in the given array.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn contains_sse(array: &[u32], needle: u32) -> bool {
+    const STEP: usize = 16;
+
+    let needle_mask = _mm_set1_epi32(needle as i32);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm_loadu_si128(ptr.cast());
+            let curr_items_p1 = _mm_loadu_si128(ptr.add(4).cast());
+            let curr_items_p2 = _mm_loadu_si128(ptr.add(8).cast());
+            let curr_items_p3 = _mm_loadu_si128(ptr.add(12).cast());
+
+            let compared = _mm_cmpeq_epi32(needle_mask, curr_items);
+            let compared1 = _mm_cmpeq_epi32(needle_mask, curr_items_p1);
+            let compared2 = _mm_cmpeq_epi32(needle_mask, curr_items_p2);
+            let compared3 = _mm_cmpeq_epi32(needle_mask, curr_items_p3);
+
+            let any = _mm_or_si128(
+                _mm_or_si128(compared, compared1),
+                _mm_or_si128(compared2, compared3),
+            );
+
+            if _mm_movemask_epi8(any) != 0 {
+                return true;
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    array[vectorized_part..].contains(&needle)
+}
+
+/// Returns the position of `needle` in `array` if the array contains it.
+/// If there are multiple occurrences of `needle` in `array`, the first index gets returned.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn find_sse(array: &[u32], needle: u32) -> Option<usize> {
+    const STEP: usize = 16;
+
+    let needle_mask = _mm_set1_epi32(needle as i32);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm_loadu_si128(ptr.cast());
+            let curr_items_p1 = _mm_loadu_si128(ptr.add(4).cast());
+            let curr_items_p2 = _mm_loadu_si128(ptr.add(8).cast());
+            let curr_items_p3 = _mm_loadu_si128(ptr.add(12).cast());
+
+            let compared = _mm_movemask_epi8(_mm_cmpeq_epi32(needle_mask, curr_items)) as u64;
+            let compared1 = _mm_movemask_epi8(_mm_cmpeq_epi32(needle_mask, curr_items_p1)) as u64;
+            let compared2 = _mm_movemask_epi8(_mm_cmpeq_epi32(needle_mask, curr_items_p2)) as u64;
+            let compared3 = _mm_movemask_epi8(_mm_cmpeq_epi32(needle_mask, curr_items_p3)) as u64;
+
+            // Each element occupies 4 bits of the combined byte mask.
+            let mask = compared | (compared1 << 16) | (compared2 << 32) | (compared3 << 48);
+            if mask != 0 {
+                return Some(i + mask.trailing_zeros() as usize / 4);
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    array[vectorized_part..]
+        .iter()
+        .position(|i| *i == needle)
+        .map(|remainder_pos| remainder_pos + vectorized_part)
+}
+
+/// Returns the amount of occurrences of `element` in `array`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn count_sse(array: &[u32], element: u32) -> usize {
+    const STEP: usize = 16;
+
+    let needle_mask = _mm_set1_epi32(element as i32);
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut sum = _mm_setzero_si128();
+    let mut sum2 = _mm_setzero_si128();
+    let mut sum3 = _mm_setzero_si128();
+    let mut sum4 = _mm_setzero_si128();
+
+    unsafe {
+        while i < iterr {
+            let curr_items = _mm_loadu_si128(ptr.cast());
+            let curr_items_2 = _mm_loadu_si128(ptr.add(4).cast());
+            let curr_items_3 = _mm_loadu_si128(ptr.add(8).cast());
+            let curr_items_4 = _mm_loadu_si128(ptr.add(12).cast());
+
+            // Matching lanes are all ones (-1), so subtracting them increments the counter.
+            sum = _mm_sub_epi32(sum, _mm_cmpeq_epi32(curr_items, needle_mask));
+            sum2 = _mm_sub_epi32(sum2, _mm_cmpeq_epi32(curr_items_2, needle_mask));
+            sum3 = _mm_sub_epi32(sum3, _mm_cmpeq_epi32(curr_items_3, needle_mask));
+            sum4 = _mm_sub_epi32(sum4, _mm_cmpeq_epi32(curr_items_4, needle_mask));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let t1 = _mm_add_epi32(sum, sum2);
+    let t2 = _mm_add_epi32(sum3, sum4);
+    let simd_res = horizontal_sum_u32_sse(_mm_add_epi32(t1, t2)) as usize;
+
+    let remainder = array[iterr..].iter().filter(|i| **i == element).count();
+    remainder + simd_res
+}
+
+/// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub fn min_sse(array: &[u32]) -> Option<u32> {
+    const STEP: usize = 16;
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm_set1_epi32(u32::MAX as i32);
+    let mut lmins2 = _mm_set1_epi32(u32::MAX as i32);
+    let mut lmins3 = _mm_set1_epi32(u32::MAX as i32);
+    let mut lmins4 = _mm_set1_epi32(u32::MAX as i32);
+
+    unsafe {
+        while i < iterr {
+            let current = _mm_loadu_si128(ptr.cast());
+            let current2 = _mm_loadu_si128(ptr.add(4).cast());
+            let current3 = _mm_loadu_si128(ptr.add(8).cast());
+            let current4 = _mm_loadu_si128(ptr.add(12).cast());
+
+            lmins1 = _mm_min_epu32(current, lmins1);
+            lmins2 = _mm_min_epu32(current2, lmins2);
+            lmins3 = _mm_min_epu32(current3, lmins3);
+            lmins4 = _mm_min_epu32(current4, lmins4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = _mm_min_epu32(lmins1, lmins2);
+    let m2 = _mm_min_epu32(lmins3, lmins4);
+    let min = horizontal_min_u32_sse(_mm_min_epu32(m1, m2));
+
+    if let Some(remainer_min) = array[iterr..].iter().min() {
+        return Some(min.min(*remainer_min));
+    }
+
+    Some(min)
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub fn max_sse(array: &[u32]) -> Option<u32> {
+    const STEP: usize = 16;
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmax1 = _mm_setzero_si128();
+    let mut lmax2 = _mm_setzero_si128();
+    let mut lmax3 = _mm_setzero_si128();
+    let mut lmax4 = _mm_setzero_si128();
+
+    unsafe {
+        while i < iterr {
+            let current = _mm_loadu_si128(ptr.cast());
+            let current2 = _mm_loadu_si128(ptr.add(4).cast());
+            let current3 = _mm_loadu_si128(ptr.add(8).cast());
+            let current4 = _mm_loadu_si128(ptr.add(12).cast());
+
+            lmax1 = _mm_max_epu32(current, lmax1);
+            lmax2 = _mm_max_epu32(current2, lmax2);
+            lmax3 = _mm_max_epu32(current3, lmax3);
+            lmax4 = _mm_max_epu32(current4, lmax4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = _mm_max_epu32(lmax1, lmax2);
+    let m2 = _mm_max_epu32(lmax3, lmax4);
+    let max = horizontal_max_u32_sse(_mm_max_epu32(m1, m2));
+
+    if let Some(remainer_max) = array[iterr..].iter().max() {
+        return Some(max.max(*remainer_max));
+    }
+
+    Some(max)
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_array_find_count_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [16, 17, 31, 32, 33, 63, 64, 65, 127, 128, 200, 513] {
+            for _ in 0..20 {
+                // Small value range to produce duplicates.
+                let vec: Vec<u32> = (0..len).map(|_| rng.next_u32() % 64).collect();
+                let needle = rng.next_u32() % 64;
+
+                let simd_find = unsafe { find_sse(&vec, needle) };
+                assert_eq!(simd_find, vec.iter().position(|i| *i == needle));
+
+                let simd_contains = unsafe { contains_sse(&vec, needle) };
+                assert_eq!(simd_contains, vec.contains(&needle));
+
+                let simd_count = unsafe { count_sse(&vec, needle) };
+                assert_eq!(simd_count, vec.iter().filter(|i| **i == needle).count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_fuzzy() {
+        if !is_x86_feature_detected!("sse4.1") {
+            return;
+        }
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [16, 17, 32, 64, 127, 128, 256, 513, 1024, 6256] {
+            let vec: Vec<u32> = (0..len).map(|_| rng.next_u32()).collect();
+
+            let simd_min = unsafe { min_sse(&vec) };
+            assert_eq!(simd_min, vec.iter().min().copied());
+
+            let simd_max = unsafe { max_sse(&vec) };
+            assert_eq!(simd_max, vec.iter().max().copied());
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use std::arch::x86_64::*;
+
+use crate::x86_64::simd_extensions::{
+    horizontal_max_u8_sse, horizontal_min_u8_sse, horizontal_sum_u64_sse,
+};
+
+/// Returns `true` if `needle` is an element in the given array.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn contains_sse(array: &[u8], needle: u8) -> bool {
+    const STEP: usize = 64;
+
+    let needle_mask = _mm_set1_epi8(needle as i8);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm_loadu_si128(ptr.cast());
+            let curr_items_p1 = _mm_loadu_si128(ptr.add(16).cast());
+            let curr_items_p2 = _mm_loadu_si128(ptr.add(32).cast());
+            let curr_items_p3 = _mm_loadu_si128(ptr.add(48).cast());
+
+            let compared = _mm_cmpeq_epi8(needle_mask, curr_items);
+            let compared1 = _mm_cmpeq_epi8(needle_mask, curr_items_p1);
+            let compared2 = _mm_cmpeq_epi8(needle_mask, curr_items_p2);
+            let compared3 = _mm_cmpeq_epi8(needle_mask, curr_items_p3);
+
+            let any = _mm_or_si128(
+                _mm_or_si128(compared, compared1),
+                _mm_or_si128(compared2, compared3),
+            );
+
+            if _mm_movemask_epi8(any) != 0 {
+                return true;
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    array[vectorized_part..].contains(&needle)
+}
+
+/// Returns the position of `needle` in `array` if the array contains it.
+/// If there are multiple occurrences of `needle` in `array`, the first index gets returned.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn find_sse(array: &[u8], needle: u8) -> Option<usize> {
+    const STEP: usize = 64;
+
+    let needle_mask = _mm_set1_epi8(needle as i8);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm_loadu_si128(ptr.cast());
+            let curr_items_p1 = _mm_loadu_si128(ptr.add(16).cast());
+            let curr_items_p2 = _mm_loadu_si128(ptr.add(32).cast());
+            let curr_items_p3 = _mm_loadu_si128(ptr.add(48).cast());
+
+            let compared = _mm_movemask_epi8(_mm_cmpeq_epi8(needle_mask, curr_items)) as u64;
+            let compared1 = _mm_movemask_epi8(_mm_cmpeq_epi8(needle_mask, curr_items_p1)) as u64;
+            let compared2 = _mm_movemask_epi8(_mm_cmpeq_epi8(needle_mask, curr_items_p2)) as u64;
+            let compared3 = _mm_movemask_epi8(_mm_cmpeq_epi8(needle_mask, curr_items_p3)) as u64;
+
+            let mask = compared | (compared1 << 16) | (compared2 << 32) | (compared3 << 48);
+            if mask != 0 {
+                return Some(i + mask.trailing_zeros() as usize);
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    array[vectorized_part..]
+        .iter()
+        .position(|i| *i == needle)
+        .map(|remainder_pos| remainder_pos + vectorized_part)
+}
+
+/// Returns the amount of occurrences of `needle` in `array`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn count_sse(array: &[u8], needle: u8) -> usize {
+    const STEP: usize = 64;
+
+    // Each iteration increments the 8 bit counters by up to 4, so they have to be flushed before they can overflow.
+    const FLUSH_INTERVAL: usize = u8::MAX as usize / 4 * STEP;
+
+    let needle_mask = _mm_set1_epi8(needle as i8);
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut total = _mm_setzero_si128();
+
+    unsafe {
+        while i < iterr {
+            let block_end = iterr.min(i + FLUSH_INTERVAL);
+            let mut counters = _mm_setzero_si128();
+
+            while i < block_end {
+                let curr_items = _mm_loadu_si128(ptr.cast());
+                let curr_items_2 = _mm_loadu_si128(ptr.add(16).cast());
+                let curr_items_3 = _mm_loadu_si128(ptr.add(32).cast());
+                let curr_items_4 = _mm_loadu_si128(ptr.add(48).cast());
+
+                // Matching lanes are all ones (-1), so subtracting them increments the counter.
+                counters = _mm_sub_epi8(counters, _mm_cmpeq_epi8(curr_items, needle_mask));
+                counters = _mm_sub_epi8(counters, _mm_cmpeq_epi8(curr_items_2, needle_mask));
+                counters = _mm_sub_epi8(counters, _mm_cmpeq_epi8(curr_items_3, needle_mask));
+                counters = _mm_sub_epi8(counters, _mm_cmpeq_epi8(curr_items_4, needle_mask));
+
+                i += STEP;
+                ptr = ptr.add(STEP);
+            }
+
+            // Sum up groups of 8 counters into 64 bit lanes.
+            total = _mm_add_epi64(total, _mm_sad_epu8(counters, _mm_setzero_si128()));
+        }
+    }
+
+    let simd_res = horizontal_sum_u64_sse(total) as usize;
+
+    let remainder = array[iterr..].iter().filter(|i| **i == needle).count();
+    remainder + simd_res
+}
+
+/// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn min_sse(array: &[u8]) -> Option<u8> {
+    const STEP: usize = 64;
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm_set1_epi8(u8::MAX as i8);
+    let mut lmins2 = _mm_set1_epi8(u8::MAX as i8);
+    let mut lmins3 = _mm_set1_epi8(u8::MAX as i8);
+    let mut lmins4 = _mm_set1_epi8(u8::MAX as i8);
+
+    unsafe {
+        while i < iterr {
+            let current = _mm_loadu_si128(ptr.cast());
+            let current2 = _mm_loadu_si128(ptr.add(16).cast());
+            let current3 = _mm_loadu_si128(ptr.add(32).cast());
+            let current4 = _mm_loadu_si128(ptr.add(48).cast());
+
+            lmins1 = _mm_min_epu8(current, lmins1);
+            lmins2 = _mm_min_epu8(current2, lmins2);
+            lmins3 = _mm_min_epu8(current3, lmins3);
+            lmins4 = _mm_min_epu8(current4, lmins4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = _mm_min_epu8(lmins1, lmins2);
+    let m2 = _mm_min_epu8(lmins3, lmins4);
+    let min = horizontal_min_u8_sse(_mm_min_epu8(m1, m2));
+
+    if let Some(remainer_min) = array[iterr..].iter().min() {
+        return Some(min.min(*remainer_min));
+    }
+
+    Some(min)
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn max_sse(array: &[u8]) -> Option<u8> {
+    const STEP: usize = 64;
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmax1 = _mm_setzero_si128();
+    let mut lmax2 = _mm_setzero_si128();
+    let mut lmax3 = _mm_setzero_si128();
+    let mut lmax4 = _mm_setzero_si128();
+
+    unsafe {
+        while i < iterr {
+            let current = _mm_loadu_si128(ptr.cast());
+            let current2 = _mm_loadu_si128(ptr.add(16).cast());
+            let current3 = _mm_loadu_si128(ptr.add(32).cast());
+            let current4 = _mm_loadu_si128(ptr.add(48).cast());
+
+            lmax1 = _mm_max_epu8(current, lmax1);
+            lmax2 = _mm_max_epu8(current2, lmax2);
+            lmax3 = _mm_max_epu8(current3, lmax3);
+            lmax4 = _mm_max_epu8(current4, lmax4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = _mm_max_epu8(lmax1, lmax2);
+    let m2 = _mm_max_epu8(lmax3, lmax4);
+    let max = horizontal_max_u8_sse(_mm_max_epu8(m1, m2));
+
+    if let Some(remainer_max) = array[iterr..].iter().max() {
+        return Some(max.max(*remainer_max));
+    }
+
+    Some(max)
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_array_find_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [32, 63, 64, 65, 127, 128, 300, 513, 1030] {
+            for _ in 0..50 {
+                let vec: Vec<u8> = (0..len).map(|_| rng.next_u32() as u8).collect();
+                let needle = rng.next_u32() as u8;
+
+                let simd_find = unsafe { find_sse(&vec, needle) };
+                assert_eq!(simd_find, vec.iter().position(|i| *i == needle));
+
+                let simd_contains = unsafe { contains_sse(&vec, needle) };
+                assert_eq!(simd_contains, vec.contains(&needle));
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_count_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        // The last lengths exceed the flush interval of the 8 bit counters.
+        for len in [0, 5, 63, 64, 65, 513, 1030, 17_000, 40_000] {
+            for _ in 0..10 {
+                // Small value range to produce many occurrences.
+                let vec: Vec<u8> = (0..len).map(|_| (rng.next_u32() % 4) as u8).collect();
+                let needle = (rng.next_u32() % 4) as u8;
+
+                let simd_count = unsafe { count_sse(&vec, needle) };
+                assert_eq!(simd_count, vec.iter().filter(|i| **i == needle).count());
+            }
+
+            let vec = vec![7u8; len];
+            assert_eq!(unsafe { count_sse(&vec, 7) }, len);
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [32, 63, 64, 65, 127, 128, 256, 513, 1024, 6256] {
+            let vec: Vec<u8> = (0..len)
+                .map(|_| (rng.next_u32() % 200) as u8 + 20)
+                .collect();
+
+            let simd_min = unsafe { min_sse(&vec) };
+            assert_eq!(simd_min, vec.iter().min().copied());
+
+            let simd_max = unsafe { max_sse(&vec) };
+            assert_eq!(simd_max, vec.iter().max().copied());
+        }
+    }
+}
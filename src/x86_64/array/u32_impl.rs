@@ -1,14 +1,24 @@
 use std::arch::x86_64::{
-    __m256i, _mm256_add_epi32, _mm256_cmpeq_epi32, _mm256_loadu_si256, _mm256_max_epu32,
-    _mm256_min_epu32, _mm256_movemask_epi8, _mm256_set1_epi32, _mm256_setzero_si256,
-    _mm256_testz_si256,
+    __m256i, _mm256_add_epi32, _mm256_and_si256, _mm256_blendv_epi8, _mm256_castsi256_ps,
+    _mm256_cmpeq_epi32, _mm256_loadu_si256, _mm256_max_epu32, _mm256_min_epu32,
+    _mm256_movemask_epi8, _mm256_movemask_ps, _mm256_or_si256, _mm256_set1_epi32,
+    _mm256_setr_epi32, _mm256_setzero_si256, _mm256_srli_epi32, _mm256_testz_si256,
+    _mm256_xor_si256,
+};
+use std::mem::transmute;
+
+use crate::original::array::{
+    all_eq_iter_u32, argmax_iter_u32, argmin_iter_u32, count_in_range_iter_u32, find_all_iter_u32,
+    find_in_range_iter_u32, mismatch_iter_u32,
 };
 
 use crate::x86_64::simd_extensions::{
-    horizontal_max_u32_avx, horizontal_min_u32_avx, negative_horizontal_sum_u32_avx,
+    horizontal_max_u32_avx, horizontal_min_u32_avx, horizontal_sum_u32_avx,
+    negative_horizontal_sum_u32_avx,
 };
 
 /// Returns `true` if `needle` is an elemen in the given array.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn contains_avx(array: &[u32], needle: u32) -> bool {
@@ -56,11 +66,12 @@ pub fn contains_avx(array: &[u32], needle: u32) -> bool {
         }
     }
 
-    array[vectorized_part..].iter().any(|i| *i == needle)
+    array[vectorized_part..].contains(&needle)
 }
 
 /// Returns the position of `needle` in `array` if the array contains it.
 /// If there are multiple occurrences of `needle` in `array`, the first index gets returned.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn find_avx(array: &[u32], needle: u32) -> Option<usize> {
@@ -122,7 +133,114 @@ pub fn find_avx(array: &[u32], needle: u32) -> Option<usize> {
         .map(|remainder_pos| remainder_pos + vectorized_part)
 }
 
+/// Returns the position of the last occurrence of `needle` in `array` if the array contains it.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn rfind_avx(array: &[u32], needle: u32) -> Option<usize> {
+    const STEP: usize = 32;
+
+    let needle_mask = _mm256_set1_epi32(needle as i32);
+
+    // The array is processed from the end, so the blocks start at `i`.
+    let mut i = array.len();
+
+    let ptr = array.as_ptr();
+
+    unsafe {
+        while i >= STEP {
+            i -= STEP;
+
+            let curr_items = _mm256_loadu_si256(ptr.add(i).cast::<__m256i>());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(i + 8).cast::<__m256i>());
+            let curr_items_p2 = _mm256_loadu_si256(ptr.add(i + 16).cast::<__m256i>());
+            let curr_items_p3 = _mm256_loadu_si256(ptr.add(i + 24).cast::<__m256i>());
+
+            let compared = _mm256_cmpeq_epi32(needle_mask, curr_items);
+            let compared1 = _mm256_cmpeq_epi32(needle_mask, curr_items_p1);
+            let compared2 = _mm256_cmpeq_epi32(needle_mask, curr_items_p2);
+            let compared3 = _mm256_cmpeq_epi32(needle_mask, curr_items_p3);
+
+            // Each item sets 4 bits of the mask.
+            if _mm256_testz_si256(compared3, compared3) == 0 {
+                let mask = _mm256_movemask_epi8(compared3) as u32;
+                let res = (31 - mask.leading_zeros()) / 4;
+                return Some(res as usize + i + 24);
+            }
+
+            if _mm256_testz_si256(compared2, compared2) == 0 {
+                let mask = _mm256_movemask_epi8(compared2) as u32;
+                let res = (31 - mask.leading_zeros()) / 4;
+                return Some(res as usize + i + 16);
+            }
+
+            if _mm256_testz_si256(compared1, compared1) == 0 {
+                let mask = _mm256_movemask_epi8(compared1) as u32;
+                let res = (31 - mask.leading_zeros()) / 4;
+                return Some(res as usize + i + 8);
+            }
+
+            if _mm256_testz_si256(compared, compared) == 0 {
+                let mask = _mm256_movemask_epi8(compared) as u32;
+                let res = (31 - mask.leading_zeros()) / 4;
+                return Some(res as usize + i);
+            }
+        }
+    }
+
+    array[..i].iter().rposition(|item| *item == needle)
+}
+
+/// Returns the start of the first block of 64 items that contains `needle` together with the mask of its occurrences
+/// in the block, whose bit `j` refers to the item at the start plus `j`. Blocks at the end of the array can start at
+/// the first occurrence instead of a multiple of 64.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_all_avx(array: &[u32], needle: u32) -> Option<(usize, u64)> {
+    const STEP: usize = 64;
+
+    let needle_mask = _mm256_set1_epi32(needle as i32);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let mut compared = [_mm256_setzero_si256(); 8];
+            let mut any = _mm256_setzero_si256();
+
+            for (j, compared) in compared.iter_mut().enumerate() {
+                let curr_items = _mm256_loadu_si256(ptr.add(i + j * 8).cast::<__m256i>());
+                *compared = _mm256_cmpeq_epi32(needle_mask, curr_items);
+                any = _mm256_or_si256(any, *compared);
+            }
+
+            if _mm256_testz_si256(any, any) == 0 {
+                // Each comparison contributes one bit per item.
+                let mut mask = 0;
+                for (j, compared) in compared.iter().enumerate() {
+                    let bits = _mm256_movemask_ps(_mm256_castsi256_ps(*compared)) as u64;
+                    mask |= bits << (j * 8);
+                }
+
+                return Some((i, mask));
+            }
+
+            i += STEP;
+        }
+    }
+
+    find_all_iter_u32(&array[vectorized_part..], needle)
+        .map(|(start, mask)| (start + vectorized_part, mask))
+}
+
 /// Returns the amount of occurrences of `needle` in `array`.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn count_avx(array: &[u32], element: u32) -> usize {
@@ -173,6 +291,7 @@ pub fn count_avx(array: &[u32], element: u32) -> usize {
 }
 
 /// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn min_avx(array: &[u32]) -> Option<u32> {
@@ -221,6 +340,7 @@ pub fn min_avx(array: &[u32]) -> Option<u32> {
 }
 
 /// Returns the largest item in the array, or `None` if the array was empty.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn max_avx(array: &[u32]) -> Option<u32> {
@@ -268,6 +388,467 @@ pub fn max_avx(array: &[u32]) -> Option<u32> {
     Some(max)
 }
 
+/// Returns the smallest and the largest item in the array, or `None` if the array was empty. Both are determined
+/// in a single pass, so the array is only loaded once.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_max_avx(array: &[u32]) -> Option<(u32, u32)> {
+    const STEP: usize = 32;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm256_set1_epi32(u32::MAX as i32);
+    let mut lmins2 = lmins1;
+    let mut lmax1 = _mm256_setzero_si256();
+    let mut lmax2 = lmax1;
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(8).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(16).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(24).cast());
+
+            lmins1 = _mm256_min_epu32(lmins1, _mm256_min_epu32(current, current2));
+            lmins2 = _mm256_min_epu32(lmins2, _mm256_min_epu32(current3, current4));
+            lmax1 = _mm256_max_epu32(lmax1, _mm256_max_epu32(current, current2));
+            lmax2 = _mm256_max_epu32(lmax2, _mm256_max_epu32(current3, current4));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let mut min = horizontal_min_u32_avx(_mm256_min_epu32(lmins1, lmins2));
+    let mut max = horizontal_max_u32_avx(_mm256_max_epu32(lmax1, lmax2));
+
+    for item in &array[iterr..] {
+        min = min.min(*item);
+        max = max.max(*item);
+    }
+
+    Some((min, max))
+}
+
+/// Returns the sum of all items in the array, or `0` if the array was empty.
+///
+/// The low and high 16 bits of the items are summed up separately in 32 bit lanes. This can't overflow for blocks of
+/// up to 65536 items, so the lanes only need to be widened once per block.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn sum_avx(array: &[u32]) -> u64 {
+    const STEP: usize = 16;
+    const BLOCK: usize = 1 << 16;
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let low_bits = _mm256_set1_epi32(0xFFFF);
+    let mut sum = 0u64;
+
+    while i < iterr {
+        let block_end = iterr.min(i + BLOCK);
+
+        let mut low1 = _mm256_setzero_si256();
+        let mut low2 = _mm256_setzero_si256();
+        let mut high1 = _mm256_setzero_si256();
+        let mut high2 = _mm256_setzero_si256();
+
+        unsafe {
+            while i < block_end {
+                let current = _mm256_loadu_si256(ptr.cast());
+                let current2 = _mm256_loadu_si256(ptr.add(8).cast());
+
+                low1 = _mm256_add_epi32(low1, _mm256_and_si256(current, low_bits));
+                low2 = _mm256_add_epi32(low2, _mm256_and_si256(current2, low_bits));
+                high1 = _mm256_add_epi32(high1, _mm256_srli_epi32::<16>(current));
+                high2 = _mm256_add_epi32(high2, _mm256_srli_epi32::<16>(current2));
+
+                i += STEP;
+                ptr = ptr.add(STEP);
+            }
+        }
+
+        let low = horizontal_sum_u32_avx(_mm256_add_epi32(low1, low2)) as u64;
+        let high = horizontal_sum_u32_avx(_mm256_add_epi32(high1, high2)) as u64;
+        sum += low + (high << 16);
+    }
+
+    sum + array[iterr..].iter().map(|i| *i as u64).sum::<u64>()
+}
+
+/// Returns the index of the first smallest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn argmin_avx(array: &[u32]) -> Option<usize> {
+    arg_extreme_avx(array, false)
+}
+
+/// Returns the index of the first largest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn argmax_avx(array: &[u32]) -> Option<usize> {
+    arg_extreme_avx(array, true)
+}
+
+/// Finds the first minimum or maximum by keeping the extreme of every lane together with the index it was found at.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn arg_extreme_avx(array: &[u32], max: bool) -> Option<usize> {
+    const STEP: usize = 32;
+
+    // The indices are tracked as 32 bit integers.
+    if array.len() > u32::MAX as usize {
+        return if max {
+            argmax_iter_u32(array)
+        } else {
+            argmin_iter_u32(array)
+        };
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut best: Option<(u32, usize)> = None;
+
+    if iterr > 0 {
+        let mut i = 0;
+        let mut ptr = array.as_ptr();
+
+        // Every lane starts with the index of its first item, which is correct if no item beats the initial value.
+        let lane_indices = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+        let mut indices1 = lane_indices;
+        let mut indices2 = _mm256_add_epi32(lane_indices, _mm256_set1_epi32(8));
+        let mut indices3 = _mm256_add_epi32(lane_indices, _mm256_set1_epi32(16));
+        let mut indices4 = _mm256_add_epi32(lane_indices, _mm256_set1_epi32(24));
+        let (mut current_indices1, mut current_indices2) = (indices1, indices2);
+        let (mut current_indices3, mut current_indices4) = (indices3, indices4);
+        let step = _mm256_set1_epi32(STEP as i32);
+
+        let init = _mm256_set1_epi32(if max { 0 } else { u32::MAX as i32 });
+        let mut extremes1 = init;
+        let mut extremes2 = init;
+        let mut extremes3 = init;
+        let mut extremes4 = init;
+
+        unsafe {
+            while i < iterr {
+                let current = _mm256_loadu_si256(ptr.cast());
+                let current2 = _mm256_loadu_si256(ptr.add(8).cast());
+                let current3 = _mm256_loadu_si256(ptr.add(16).cast());
+                let current4 = _mm256_loadu_si256(ptr.add(24).cast());
+
+                (extremes1, indices1) =
+                    update_extremes(extremes1, indices1, current, current_indices1, max);
+                (extremes2, indices2) =
+                    update_extremes(extremes2, indices2, current2, current_indices2, max);
+                (extremes3, indices3) =
+                    update_extremes(extremes3, indices3, current3, current_indices3, max);
+                (extremes4, indices4) =
+                    update_extremes(extremes4, indices4, current4, current_indices4, max);
+
+                current_indices1 = _mm256_add_epi32(current_indices1, step);
+                current_indices2 = _mm256_add_epi32(current_indices2, step);
+                current_indices3 = _mm256_add_epi32(current_indices3, step);
+                current_indices4 = _mm256_add_epi32(current_indices4, step);
+
+                i += STEP;
+                ptr = ptr.add(STEP);
+            }
+        }
+
+        // Safety: we can safely transmute a __m256i to [u32; 8]
+        let extremes: [[u32; 8]; 4] =
+            unsafe { transmute([extremes1, extremes2, extremes3, extremes4]) };
+        let indices: [[u32; 8]; 4] = unsafe { transmute([indices1, indices2, indices3, indices4]) };
+
+        for (value, index) in extremes.as_flattened().iter().zip(indices.as_flattened()) {
+            let index = *index as usize;
+
+            let better = match best {
+                None => true,
+                Some((best_value, best_index)) if *value == best_value => index < best_index,
+                Some((best_value, _)) => (*value > best_value) == max,
+            };
+
+            if better {
+                best = Some((*value, index));
+            }
+        }
+    }
+
+    for (index, value) in array.iter().enumerate().skip(iterr) {
+        let better = match best {
+            None => true,
+            Some((best_value, _)) => {
+                if max {
+                    *value > best_value
+                } else {
+                    *value < best_value
+                }
+            }
+        };
+
+        if better {
+            best = Some((*value, index));
+        }
+    }
+
+    best.map(|(_, index)| index)
+}
+
+/// Keeps the extreme of `extremes` and `current` in every lane, together with the index it was found at. Equal items
+/// keep the earlier index.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn update_extremes(
+    extremes: __m256i,
+    indices: __m256i,
+    current: __m256i,
+    current_indices: __m256i,
+    max: bool,
+) -> (__m256i, __m256i) {
+    if max {
+        let keep = _mm256_cmpeq_epi32(_mm256_min_epu32(current, extremes), current);
+        (
+            _mm256_max_epu32(current, extremes),
+            _mm256_blendv_epi8(current_indices, indices, keep),
+        )
+    } else {
+        let keep = _mm256_cmpeq_epi32(_mm256_max_epu32(current, extremes), current);
+        (
+            _mm256_min_epu32(current, extremes),
+            _mm256_blendv_epi8(current_indices, indices, keep),
+        )
+    }
+}
+
+/// Returns the position of the first item that differs between `array` and `other`, or the length of the shorter
+/// one if it is a prefix of the longer one. Returns `None` if both are equal.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn mismatch_avx(array: &[u32], other: &[u32]) -> Option<usize> {
+    const STEP: usize = 32;
+
+    let len = array.len().min(other.len());
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let ptr = array.as_ptr();
+    let other_ptr = other.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let mut compared = [_mm256_setzero_si256(); 4];
+            for (j, compared) in compared.iter_mut().enumerate() {
+                let curr_items = _mm256_loadu_si256(ptr.add(i + j * 8).cast::<__m256i>());
+                let other_items = _mm256_loadu_si256(other_ptr.add(i + j * 8).cast::<__m256i>());
+                *compared = _mm256_cmpeq_epi32(curr_items, other_items);
+            }
+
+            let equal = _mm256_and_si256(
+                _mm256_and_si256(compared[0], compared[1]),
+                _mm256_and_si256(compared[2], compared[3]),
+            );
+
+            if _mm256_movemask_epi8(equal) != -1 {
+                for (j, compared) in compared.iter().enumerate() {
+                    // Each item sets 4 bits of the mask.
+                    let mask = !_mm256_movemask_epi8(*compared) as u32;
+                    if mask != 0 {
+                        return Some(mask.trailing_zeros() as usize / 4 + i + j * 8);
+                    }
+                }
+            }
+
+            i += STEP;
+        }
+    }
+
+    mismatch_iter_u32(&array[i..], &other[i..]).map(|position| position + i)
+}
+
+/// Returns a mask of the items of `items` that lie within `lo..=hi`, which must not be empty.
+#[inline]
+#[target_feature(enable = "avx2")]
+fn in_range_avx(items: __m256i, lo: __m256i, hi: __m256i) -> __m256i {
+    // Only items within the range are unchanged by clamping them, which compares unsigned.
+    _mm256_cmpeq_epi32(_mm256_min_epu32(_mm256_max_epu32(items, lo), hi), items)
+}
+
+/// Returns the amount of items within `lo..=hi`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn count_in_range_avx(array: &[u32], lo: u32, hi: u32) -> usize {
+    const STEP: usize = 32;
+
+    if lo > hi {
+        return 0;
+    }
+
+    let lo_mask = _mm256_set1_epi32(lo as i32);
+    let hi_mask = _mm256_set1_epi32(hi as i32);
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut sum = _mm256_setzero_si256();
+    let mut sum2 = _mm256_setzero_si256();
+    let mut sum3 = _mm256_setzero_si256();
+    let mut sum4 = _mm256_setzero_si256();
+
+    unsafe {
+        while i < iterr {
+            let curr_items = _mm256_loadu_si256(ptr.cast::<__m256i>());
+            let curr_items_2 = _mm256_loadu_si256(ptr.add(8).cast::<__m256i>());
+            let curr_items_3 = _mm256_loadu_si256(ptr.add(16).cast::<__m256i>());
+            let curr_items_4 = _mm256_loadu_si256(ptr.add(24).cast::<__m256i>());
+
+            sum = _mm256_add_epi32(sum, in_range_avx(curr_items, lo_mask, hi_mask));
+            sum2 = _mm256_add_epi32(sum2, in_range_avx(curr_items_2, lo_mask, hi_mask));
+            sum3 = _mm256_add_epi32(sum3, in_range_avx(curr_items_3, lo_mask, hi_mask));
+            sum4 = _mm256_add_epi32(sum4, in_range_avx(curr_items_4, lo_mask, hi_mask));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let t1 = _mm256_add_epi32(sum, sum2);
+    let t2 = _mm256_add_epi32(sum3, sum4);
+    let sum = _mm256_add_epi32(t1, t2);
+    let simd_res = negative_horizontal_sum_u32_avx(sum) as usize;
+
+    simd_res + count_in_range_iter_u32(&array[iterr..], lo, hi)
+}
+
+/// Returns the position of the first item within `lo..=hi` if there is any.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_in_range_avx(array: &[u32], lo: u32, hi: u32) -> Option<usize> {
+    const STEP: usize = 32;
+
+    if lo > hi {
+        return None;
+    }
+
+    let lo_mask = _mm256_set1_epi32(lo as i32);
+    let hi_mask = _mm256_set1_epi32(hi as i32);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let mut compared = [_mm256_setzero_si256(); 4];
+            for (j, compared) in compared.iter_mut().enumerate() {
+                let curr_items = _mm256_loadu_si256(ptr.add(i + j * 8).cast::<__m256i>());
+                *compared = in_range_avx(curr_items, lo_mask, hi_mask);
+            }
+
+            let any = _mm256_or_si256(
+                _mm256_or_si256(compared[0], compared[1]),
+                _mm256_or_si256(compared[2], compared[3]),
+            );
+
+            if _mm256_testz_si256(any, any) == 0 {
+                for (j, compared) in compared.iter().enumerate() {
+                    // Each item sets 4 bits of the mask.
+                    let mask = _mm256_movemask_epi8(*compared) as u32;
+                    if mask != 0 {
+                        return Some(mask.trailing_zeros() as usize / 4 + i + j * 8);
+                    }
+                }
+            }
+
+            i += STEP;
+        }
+    }
+
+    find_in_range_iter_u32(&array[vectorized_part..], lo, hi)
+        .map(|position| position + vectorized_part)
+}
+
+/// Returns `true` if all items are `value`, which is the case for an empty array.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn all_eq_avx(array: &[u32], value: u32) -> bool {
+    const STEP: usize = 32;
+
+    let value_mask = _mm256_set1_epi32(value as i32);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm256_loadu_si256(ptr.cast::<__m256i>());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(8).cast::<__m256i>());
+            let curr_items_p2 = _mm256_loadu_si256(ptr.add(16).cast::<__m256i>());
+            let curr_items_p3 = _mm256_loadu_si256(ptr.add(24).cast::<__m256i>());
+
+            // Bits of items other than `value` remain set.
+            let differences = _mm256_or_si256(
+                _mm256_or_si256(
+                    _mm256_xor_si256(value_mask, curr_items),
+                    _mm256_xor_si256(value_mask, curr_items_p1),
+                ),
+                _mm256_or_si256(
+                    _mm256_xor_si256(value_mask, curr_items_p2),
+                    _mm256_xor_si256(value_mask, curr_items_p3),
+                ),
+            );
+
+            if _mm256_testz_si256(differences, differences) == 0 {
+                return false;
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    all_eq_iter_u32(&array[vectorized_part..], value)
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashSet;
@@ -277,6 +858,7 @@ mod test {
     use rstest::rstest;
 
     use super::*;
+    use crate::original::array::{rfind_iter_u32, sum_iter_u32};
 
     fn random_array_with_count(
         rng: &mut impl Rng,
@@ -336,13 +918,10 @@ mod test {
             })
             .collect();
 
-        match (value, index) {
-            (Some(val), Some(index)) => {
-                if index < len {
-                    vec[index] = val;
-                }
-            }
-            _ => (),
+        if let (Some(val), Some(index)) = (value, index)
+            && index < len
+        {
+            vec[index] = val;
         }
 
         vec
@@ -411,6 +990,63 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_array_find_all_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 63, 64, 65, 127, 128, 129, 170, 200, 513] {
+            for range in [8, 200] {
+                let vec: Vec<u32> = (0..size).map(|_| rng.next_u32() % range).collect();
+
+                for value in 0..10 {
+                    let mut positions = Vec::new();
+                    let mut searched = 0;
+
+                    while let Some((start, mut mask)) =
+                        unsafe { find_all_avx(&vec[searched..], value) }
+                    {
+                        assert_ne!(mask, 0);
+
+                        let start = searched + start;
+                        while mask != 0 {
+                            positions.push(start + mask.trailing_zeros() as usize);
+                            mask &= mask - 1;
+                        }
+
+                        searched = (start + 64).min(size);
+                    }
+
+                    let expected: Vec<usize> = (0..size).filter(|i| vec[*i] == value).collect();
+                    assert_eq!(positions, expected, "{value} in {vec:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_rfind_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 31, 32, 33, 127, 128, 129, 170, 256, 513] {
+            // Small values, so most needles occur several times.
+            let vec: Vec<u32> = (0..size).map(|_| rng.next_u32() % 64).collect();
+
+            for value in 0..70 {
+                let simd_result = unsafe { rfind_avx(&vec, value) };
+                assert_eq!(
+                    simd_result,
+                    rfind_iter_u32(&vec, value),
+                    "{value} in {vec:?}"
+                );
+            }
+
+            for index in 0..size {
+                let vec = random_array_with_value(&mut rng, size, Some(70), Some(index));
+                assert_eq!(unsafe { rfind_avx(&vec, 70) }, Some(index));
+            }
+        }
+    }
+
     #[rstest]
     // (len, count)
     #[case(32, 0)]
@@ -465,4 +1101,120 @@ mod test {
             assert_eq!(simd_max, real_max);
         }
     }
+
+    #[test]
+    fn test_array_min_max_single_pass_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 31, 32, 33, 64, 127, 513, 1024, 6256] {
+            let vec: Vec<u32> = (0..len).map(|_| rng.next_u32()).collect();
+
+            let expected = vec.iter().min().copied().zip(vec.iter().max().copied());
+            assert_eq!(unsafe { min_max_avx(&vec) }, expected);
+        }
+
+        for vec in [vec![0u32; 200], vec![u32::MAX; 200]] {
+            assert_eq!(unsafe { min_max_avx(&vec) }, Some((vec[0], vec[0])));
+        }
+    }
+
+    #[test]
+    fn test_array_arg_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 7, 31, 32, 33, 64, 127, 128, 513, 1024, 6256] {
+            // Few distinct values, so the extremes occur several times.
+            for modulo in [3, 1000, u32::MAX] {
+                let vec: Vec<u32> = (0..len).map(|_| rng.next_u32() % modulo).collect();
+
+                assert_eq!(unsafe { argmin_avx(&vec) }, argmin_iter_u32(&vec));
+                assert_eq!(unsafe { argmax_avx(&vec) }, argmax_iter_u32(&vec));
+            }
+        }
+
+        for value in [0, u32::MAX] {
+            let vec = vec![value; 100];
+            assert_eq!(unsafe { argmin_avx(&vec) }, Some(0));
+            assert_eq!(unsafe { argmax_avx(&vec) }, Some(0));
+        }
+    }
+
+    #[test]
+    fn test_array_sum_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        // Lengths around the block size, after which the partial sums are widened.
+        for len in [0, 1, 15, 16, 17, 513, 65_535, 65_536, 65_537, 200_000] {
+            let vec: Vec<u32> = (0..len).map(|_| rng.next_u32()).collect();
+            assert_eq!(unsafe { sum_avx(&vec) }, sum_iter_u32(&vec));
+        }
+
+        let vec = vec![u32::MAX; 200_000];
+        assert_eq!(unsafe { sum_avx(&vec) }, u32::MAX as u64 * 200_000);
+    }
+
+    #[test]
+    fn test_array_mismatch_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 7, 8, 31, 32, 33, 127, 128, 129, 300, 513] {
+            let vec: Vec<u32> = (0..size).map(|_| rng.next_u32()).collect();
+
+            assert_eq!(unsafe { mismatch_avx(&vec, &vec) }, None);
+
+            for index in 0..size {
+                let mut other = vec.clone();
+                other[index] ^= 1;
+                assert_eq!(unsafe { mismatch_avx(&vec, &other) }, Some(index));
+                assert_eq!(unsafe { mismatch_avx(&other[..index], &vec) }, Some(index));
+                assert_eq!(unsafe { mismatch_avx(&vec, &vec[..index]) }, Some(index));
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_all_eq_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 7, 8, 31, 32, 33, 127, 128, 129, 300, 513] {
+            let value = rng.next_u32();
+            let vec = vec![value; size];
+
+            assert!(unsafe { all_eq_avx(&vec, value) });
+            assert_eq!(unsafe { all_eq_avx(&vec, !value) }, size == 0);
+
+            for index in 0..size {
+                let mut other = vec.clone();
+                other[index] ^= 1 << 31;
+                assert!(!unsafe { all_eq_avx(&other, value) });
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_in_range_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 31, 32, 33, 127, 128, 129, 300, 513, 40_000] {
+            let vec: Vec<u32> = (0..size).map(|_| rng.next_u32()).collect();
+
+            for _ in 0..20 {
+                // Half of the random bounds have the top bit set, which needs unsigned comparisons.
+                let (lo, hi) = (rng.next_u32(), rng.next_u32());
+
+                assert_eq!(
+                    unsafe { count_in_range_avx(&vec, lo, hi) },
+                    count_in_range_iter_u32(&vec, lo, hi),
+                    "{lo}..={hi}"
+                );
+                assert_eq!(
+                    unsafe { find_in_range_avx(&vec, lo, hi) },
+                    find_in_range_iter_u32(&vec, lo, hi),
+                    "{lo}..={hi}"
+                );
+            }
+
+            assert_eq!(unsafe { count_in_range_avx(&vec, 0, u32::MAX) }, size);
+        }
+    }
 }
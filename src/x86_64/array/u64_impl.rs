@@ -0,0 +1,287 @@
+use std::arch::x86_64::*;
+use std::slice;
+
+use crate::x86_64::array::i64_impl;
+use crate::x86_64::array::i64_impl::{max_epi64, min_epi64};
+use crate::x86_64::simd_extensions::{horizontal_max_u64_avx, horizontal_min_u64_avx};
+
+/// Reinterprets the array as signed integers, as equality doesn't depend on the sign.
+#[inline]
+fn as_signed(array: &[u64]) -> &[i64] {
+    // Safety: u64 and i64 have the same size and alignment, and every bit pattern is valid for both.
+    unsafe { slice::from_raw_parts(array.as_ptr().cast(), array.len()) }
+}
+
+/// Flips the highest bit of every lane. This maps unsigned values to signed ones with the same order, as AVX2 only
+/// provides signed comparisons of 64 bit integers.
+#[inline]
+#[target_feature(enable = "avx2")]
+fn flip_sign(a: __m256i) -> __m256i {
+    _mm256_xor_si256(a, _mm256_set1_epi64x(i64::MIN))
+}
+
+/// Returns `true` if `needle` is an element in the given array.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn contains_avx(array: &[u64], needle: u64) -> bool {
+    i64_impl::contains_avx(as_signed(array), needle as i64)
+}
+
+/// Returns the position of `needle` in `array` if the array contains it.
+/// If there are multiple occurrences of `needle` in `array`, the first index gets returned.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_avx(array: &[u64], needle: u64) -> Option<usize> {
+    i64_impl::find_avx(as_signed(array), needle as i64)
+}
+
+/// Returns the amount of occurrences of `needle` in `array`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn count_avx(array: &[u64], needle: u64) -> usize {
+    i64_impl::count_avx(as_signed(array), needle as i64)
+}
+
+/// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_avx(array: &[u64]) -> Option<u64> {
+    const STEP: usize = 16;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    // The accumulators hold sign flipped values, so the signed minimum can be used.
+    let mut lmins1 = flip_sign(_mm256_set1_epi64x(u64::MAX as i64));
+    let mut lmins2 = lmins1;
+    let mut lmins3 = lmins1;
+    let mut lmins4 = lmins1;
+
+    unsafe {
+        while i < iterr {
+            let current = flip_sign(_mm256_loadu_si256(ptr.cast()));
+            let current2 = flip_sign(_mm256_loadu_si256(ptr.add(4).cast()));
+            let current3 = flip_sign(_mm256_loadu_si256(ptr.add(8).cast()));
+            let current4 = flip_sign(_mm256_loadu_si256(ptr.add(12).cast()));
+
+            lmins1 = min_epi64(lmins1, current);
+            lmins2 = min_epi64(lmins2, current2);
+            lmins3 = min_epi64(lmins3, current3);
+            lmins4 = min_epi64(lmins4, current4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = min_epi64(lmins1, lmins2);
+    let m2 = min_epi64(lmins3, lmins4);
+    let min = horizontal_min_u64_avx(flip_sign(min_epi64(m1, m2)));
+
+    if let Some(remainer_min) = array[iterr..].iter().min() {
+        return Some(min.min(*remainer_min));
+    }
+
+    Some(min)
+}
+
+/// Returns the largest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn max_avx(array: &[u64]) -> Option<u64> {
+    const STEP: usize = 16;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    // The accumulators hold sign flipped values, so the signed maximum can be used.
+    let mut lmax1 = flip_sign(_mm256_setzero_si256());
+    let mut lmax2 = lmax1;
+    let mut lmax3 = lmax1;
+    let mut lmax4 = lmax1;
+
+    unsafe {
+        while i < iterr {
+            let current = flip_sign(_mm256_loadu_si256(ptr.cast()));
+            let current2 = flip_sign(_mm256_loadu_si256(ptr.add(4).cast()));
+            let current3 = flip_sign(_mm256_loadu_si256(ptr.add(8).cast()));
+            let current4 = flip_sign(_mm256_loadu_si256(ptr.add(12).cast()));
+
+            lmax1 = max_epi64(lmax1, current);
+            lmax2 = max_epi64(lmax2, current2);
+            lmax3 = max_epi64(lmax3, current3);
+            lmax4 = max_epi64(lmax4, current4);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let m1 = max_epi64(lmax1, lmax2);
+    let m2 = max_epi64(lmax3, lmax4);
+    let max = horizontal_max_u64_avx(flip_sign(max_epi64(m1, m2)));
+
+    if let Some(remainer_max) = array[iterr..].iter().max() {
+        return Some(max.max(*remainer_max));
+    }
+
+    Some(max)
+}
+
+/// Returns the smallest and the largest item in the array, or `None` if the array was empty. Both are determined
+/// in a single pass, so the array is only loaded once.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_max_avx(array: &[u64]) -> Option<(u64, u64)> {
+    const STEP: usize = 16;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    // The accumulators hold sign flipped values, so the signed minimum and maximum can be used.
+    let mut lmins1 = flip_sign(_mm256_set1_epi64x(u64::MAX as i64));
+    let mut lmins2 = lmins1;
+    let mut lmax1 = flip_sign(_mm256_setzero_si256());
+    let mut lmax2 = lmax1;
+
+    unsafe {
+        while i < iterr {
+            let current = flip_sign(_mm256_loadu_si256(ptr.cast()));
+            let current2 = flip_sign(_mm256_loadu_si256(ptr.add(4).cast()));
+            let current3 = flip_sign(_mm256_loadu_si256(ptr.add(8).cast()));
+            let current4 = flip_sign(_mm256_loadu_si256(ptr.add(12).cast()));
+
+            lmins1 = min_epi64(lmins1, min_epi64(current, current2));
+            lmins2 = min_epi64(lmins2, min_epi64(current3, current4));
+            lmax1 = max_epi64(lmax1, max_epi64(current, current2));
+            lmax2 = max_epi64(lmax2, max_epi64(current3, current4));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let mut min = horizontal_min_u64_avx(flip_sign(min_epi64(lmins1, lmins2)));
+    let mut max = horizontal_max_u64_avx(flip_sign(max_epi64(lmax1, lmax2)));
+
+    for item in &array[iterr..] {
+        min = min.min(*item);
+        max = max.max(*item);
+    }
+
+    Some((min, max))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_array_find_count_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 3, 4, 15, 16, 17, 31, 32, 33, 300, 513, 1030] {
+            for _ in 0..20 {
+                // Small value range, including values with the highest bit set, to produce duplicates.
+                let base = u64::MAX / 2 - 32;
+                let vec: Vec<u64> = (0..len).map(|_| rng.next_u64() % 64 + base).collect();
+                let needle = rng.next_u64() % 70 + base;
+
+                let simd_find = unsafe { find_avx(&vec, needle) };
+                assert_eq!(simd_find, vec.iter().position(|i| *i == needle));
+
+                let simd_contains = unsafe { contains_avx(&vec, needle) };
+                assert_eq!(simd_contains, vec.contains(&needle));
+
+                let simd_count = unsafe { count_avx(&vec, needle) };
+                assert_eq!(simd_count, vec.iter().filter(|i| **i == needle).count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 4, 15, 16, 17, 64, 127, 128, 513, 1024, 6256] {
+            let vec: Vec<u64> = (0..len).map(|_| rng.next_u64()).collect();
+
+            let simd_min = unsafe { min_avx(&vec) };
+            assert_eq!(simd_min, vec.iter().min().copied());
+
+            let simd_max = unsafe { max_avx(&vec) };
+            assert_eq!(simd_max, vec.iter().max().copied());
+
+            // Values on both sides of the sign bit, which a signed comparison would order wrong.
+            let vec: Vec<u64> = (0..len)
+                .map(|_| rng.next_u64() % 8 + u64::MAX / 2 - 4)
+                .collect();
+
+            let simd_min = unsafe { min_avx(&vec) };
+            assert_eq!(simd_min, vec.iter().min().copied());
+
+            let simd_max = unsafe { max_avx(&vec) };
+            assert_eq!(simd_max, vec.iter().max().copied());
+        }
+
+        for vec in [vec![0u64; 200], vec![1 << 63; 200], vec![u64::MAX; 200]] {
+            assert_eq!(unsafe { min_avx(&vec) }, vec.iter().min().copied());
+            assert_eq!(unsafe { max_avx(&vec) }, vec.iter().max().copied());
+        }
+    }
+
+    #[test]
+    fn test_array_min_max_single_pass_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 15, 16, 17, 64, 127, 513, 1024, 6256] {
+            // Values on both sides of the sign bit, which a signed comparison would order wrong.
+            let vec: Vec<u64> = (0..len)
+                .map(|_| rng.next_u64() % 8 + u64::MAX / 2 - 4)
+                .collect();
+
+            let expected = vec.iter().min().copied().zip(vec.iter().max().copied());
+            assert_eq!(unsafe { min_max_avx(&vec) }, expected);
+        }
+
+        for vec in [vec![0u64; 200], vec![u64::MAX; 200]] {
+            assert_eq!(unsafe { min_max_avx(&vec) }, Some((vec[0], vec[0])));
+        }
+    }
+}
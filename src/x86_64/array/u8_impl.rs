@@ -1,8 +1,17 @@
 use std::arch::x86_64::*;
+use std::mem::transmute;
 
-use crate::x86_64::simd_extensions::{horizontal_max_u8_avx, horizontal_min_u8_avx};
+use crate::original::array::{
+    all_eq_iter_u8, count_in_range_iter_u8, find_all_iter_u8, find_any_of_iter_u8,
+    find_any2_iter_u8, find_any3_iter_u8, find_in_range_iter_u8, find_subslice_iter_u8,
+    mismatch_iter_u8,
+};
+use crate::x86_64::simd_extensions::{
+    horizontal_max_u8_avx, horizontal_min_u8_avx, horizontal_sum_u64_avx,
+};
 
 /// Returns `true` if `needle` is an elemen in the given array.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn contains_avx(array: &[u8], needle: u8) -> bool {
@@ -73,9 +82,10 @@ pub fn contains_avx(array: &[u8], needle: u8) -> bool {
         }
     }
 
-    array[(len - half_m)..].iter().any(|i| *i == needle)
+    array[(len - half_m)..].contains(&needle)
 }
 /// Returns `true` if `needle` is an elemen in the given array.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn find_avx(array: &[u8], needle: u8) -> Option<usize> {
@@ -154,13 +164,13 @@ pub fn find_avx(array: &[u8], needle: u8) -> Option<usize> {
             if _mm_testz_si128(compared, compared) == 0 {
                 let mask = _mm_movemask_epi8(compared);
                 let res = mask.trailing_zeros();
-                return Some(res as usize + i);
+                return Some(res as usize + vectorized_part + i);
             }
 
             if _mm_testz_si128(compared1, compared1) == 0 {
                 let mask = _mm_movemask_epi8(compared1);
                 let res = mask.trailing_zeros();
-                return Some(res as usize + i + 16);
+                return Some(res as usize + vectorized_part + i + 16);
             }
 
             ptr = ptr.add(STEP_HALF);
@@ -175,7 +185,413 @@ pub fn find_avx(array: &[u8], needle: u8) -> Option<usize> {
         .map(|i| remaining + i)
 }
 
+/// Returns the position of the last occurrence of `needle` in `array` if the array contains it.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn rfind_avx(array: &[u8], needle: u8) -> Option<usize> {
+    const STEP: usize = 128;
+    const STEP_QUARTER: usize = 32;
+
+    let needle_mask = _mm256_set1_epi8(needle as i8);
+
+    // The array is processed from the end, so the blocks start at `i`.
+    let mut i = array.len();
+
+    let ptr = array.as_ptr();
+
+    unsafe {
+        while i >= STEP {
+            i -= STEP;
+
+            let curr_items = _mm256_loadu_si256(ptr.add(i).cast());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(i + 32).cast());
+            let curr_items_p2 = _mm256_loadu_si256(ptr.add(i + 64).cast());
+            let curr_items_p3 = _mm256_loadu_si256(ptr.add(i + 96).cast());
+
+            let compared = _mm256_cmpeq_epi8(needle_mask, curr_items);
+            let compared1 = _mm256_cmpeq_epi8(needle_mask, curr_items_p1);
+            let compared2 = _mm256_cmpeq_epi8(needle_mask, curr_items_p2);
+            let compared3 = _mm256_cmpeq_epi8(needle_mask, curr_items_p3);
+
+            if _mm256_testz_si256(compared3, compared3) == 0 {
+                let mask = _mm256_movemask_epi8(compared3) as u32;
+                let res = 31 - mask.leading_zeros();
+                return Some(res as usize + i + 96);
+            }
+
+            if _mm256_testz_si256(compared2, compared2) == 0 {
+                let mask = _mm256_movemask_epi8(compared2) as u32;
+                let res = 31 - mask.leading_zeros();
+                return Some(res as usize + i + 64);
+            }
+
+            if _mm256_testz_si256(compared1, compared1) == 0 {
+                let mask = _mm256_movemask_epi8(compared1) as u32;
+                let res = 31 - mask.leading_zeros();
+                return Some(res as usize + i + 32);
+            }
+
+            if _mm256_testz_si256(compared, compared) == 0 {
+                let mask = _mm256_movemask_epi8(compared) as u32;
+                let res = 31 - mask.leading_zeros();
+                return Some(res as usize + i);
+            }
+        }
+
+        while i >= STEP_QUARTER {
+            i -= STEP_QUARTER;
+
+            let curr_items = _mm256_loadu_si256(ptr.add(i).cast());
+            let compared = _mm256_cmpeq_epi8(needle_mask, curr_items);
+
+            if _mm256_testz_si256(compared, compared) == 0 {
+                let mask = _mm256_movemask_epi8(compared) as u32;
+                let res = 31 - mask.leading_zeros();
+                return Some(res as usize + i);
+            }
+        }
+    }
+
+    array[..i].iter().rposition(|item| *item == needle)
+}
+
+/// Returns the start of the first 64 byte block that contains `needle` together with the mask of its occurrences in
+/// the block, whose bit `j` refers to the item at the start plus `j`. Blocks at the end of the array can start at the
+/// first occurrence instead of a multiple of 64.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_all_avx(array: &[u8], needle: u8) -> Option<(usize, u64)> {
+    const STEP: usize = 128;
+    const STEP_HALF: usize = 64;
+
+    let needle_mask = _mm256_set1_epi8(needle as i8);
+
+    let len = array.len();
+    let mut i = 0;
+
+    let ptr = array.as_ptr();
+
+    unsafe {
+        while i + STEP <= len {
+            let curr_items = _mm256_loadu_si256(ptr.add(i).cast());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(i + 32).cast());
+            let curr_items_p2 = _mm256_loadu_si256(ptr.add(i + 64).cast());
+            let curr_items_p3 = _mm256_loadu_si256(ptr.add(i + 96).cast());
+
+            let compared = _mm256_cmpeq_epi8(needle_mask, curr_items);
+            let compared1 = _mm256_cmpeq_epi8(needle_mask, curr_items_p1);
+            let compared2 = _mm256_cmpeq_epi8(needle_mask, curr_items_p2);
+            let compared3 = _mm256_cmpeq_epi8(needle_mask, curr_items_p3);
+
+            let any = _mm256_or_si256(
+                _mm256_or_si256(compared, compared1),
+                _mm256_or_si256(compared2, compared3),
+            );
+
+            if _mm256_testz_si256(any, any) == 0 {
+                let mask = movemask_64_avx(compared, compared1);
+                if mask != 0 {
+                    return Some((i, mask));
+                }
+
+                return Some((i + 64, movemask_64_avx(compared2, compared3)));
+            }
+
+            i += STEP;
+        }
+
+        if i + STEP_HALF <= len {
+            let curr_items = _mm256_loadu_si256(ptr.add(i).cast());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(i + 32).cast());
+
+            let mask = movemask_64_avx(
+                _mm256_cmpeq_epi8(needle_mask, curr_items),
+                _mm256_cmpeq_epi8(needle_mask, curr_items_p1),
+            );
+            if mask != 0 {
+                return Some((i, mask));
+            }
+
+            i += STEP_HALF;
+        }
+    }
+
+    find_all_iter_u8(&array[i..], needle).map(|(start, mask)| (start + i, mask))
+}
+
+/// Scans `$array` for the first byte `$matches` sets to `0xFF`, computing it from the 32 bytes `$items`, and returns
+/// its position from the enclosing function. Evaluates to the start of the remainder shorter than 32 bytes, which
+/// the caller has to scan itself.
+macro_rules! find_first_match {
+    ($array:expr, |$items:ident| $matches:expr) => {{
+        const STEP: usize = 128;
+        const STEP_QUARTER: usize = 32;
+
+        let array: &[u8] = $array;
+        let len = array.len();
+        let mut i = 0;
+
+        let ptr = array.as_ptr();
+
+        unsafe {
+            while i + STEP <= len {
+                let compared = {
+                    let $items = _mm256_loadu_si256(ptr.add(i).cast());
+                    $matches
+                };
+                let compared1 = {
+                    let $items = _mm256_loadu_si256(ptr.add(i + 32).cast());
+                    $matches
+                };
+                let compared2 = {
+                    let $items = _mm256_loadu_si256(ptr.add(i + 64).cast());
+                    $matches
+                };
+                let compared3 = {
+                    let $items = _mm256_loadu_si256(ptr.add(i + 96).cast());
+                    $matches
+                };
+
+                let any = _mm256_or_si256(
+                    _mm256_or_si256(compared, compared1),
+                    _mm256_or_si256(compared2, compared3),
+                );
+
+                if _mm256_testz_si256(any, any) == 0 {
+                    let mask = movemask_64_avx(compared, compared1);
+                    if mask != 0 {
+                        return Some(mask.trailing_zeros() as usize + i);
+                    }
+
+                    let mask = movemask_64_avx(compared2, compared3);
+                    return Some(mask.trailing_zeros() as usize + i + 64);
+                }
+
+                i += STEP;
+            }
+
+            while i + STEP_QUARTER <= len {
+                let compared = {
+                    let $items = _mm256_loadu_si256(ptr.add(i).cast());
+                    $matches
+                };
+
+                if _mm256_testz_si256(compared, compared) == 0 {
+                    let mask = _mm256_movemask_epi8(compared);
+                    return Some(mask.trailing_zeros() as usize + i);
+                }
+
+                i += STEP_QUARTER;
+            }
+        }
+
+        i
+    }};
+}
+
+/// Returns the position of the first item that is `a` or `b` if the array contains any of them.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_any2_avx(array: &[u8], a: u8, b: u8) -> Option<usize> {
+    let a_mask = _mm256_set1_epi8(a as i8);
+    let b_mask = _mm256_set1_epi8(b as i8);
+
+    let i = find_first_match!(array, |items| _mm256_or_si256(
+        _mm256_cmpeq_epi8(a_mask, items),
+        _mm256_cmpeq_epi8(b_mask, items)
+    ));
+
+    find_any2_iter_u8(&array[i..], a, b).map(|position| position + i)
+}
+
+/// Returns the position of the first item that is `a`, `b` or `c` if the array contains any of them.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_any3_avx(array: &[u8], a: u8, b: u8, c: u8) -> Option<usize> {
+    let a_mask = _mm256_set1_epi8(a as i8);
+    let b_mask = _mm256_set1_epi8(b as i8);
+    let c_mask = _mm256_set1_epi8(c as i8);
+
+    let i = find_first_match!(array, |items| _mm256_or_si256(
+        _mm256_or_si256(
+            _mm256_cmpeq_epi8(a_mask, items),
+            _mm256_cmpeq_epi8(b_mask, items)
+        ),
+        _mm256_cmpeq_epi8(c_mask, items)
+    ));
+
+    find_any3_iter_u8(&array[i..], a, b, c).map(|position| position + i)
+}
+
+/// Returns the position of the first item contained in `needles` if there is any.
+///
+/// The set of needles is stored as a bitmap of 16 rows, one for each low nibble, with a bit for each of the 16 high
+/// nibbles. Each row is split into two tables of 8 bits, which are looked up by the low nibble with `vpshufb`, and
+/// the top bit of the item selects the table.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_any_of_avx(array: &[u8], needles: &[u8]) -> Option<usize> {
+    let mut low_rows = [0u8; 16];
+    let mut high_rows = [0u8; 16];
+
+    for needle in needles {
+        let rows = if needle & 0x80 == 0 {
+            &mut low_rows
+        } else {
+            &mut high_rows
+        };
+        rows[(needle & 0x0F) as usize] |= 1 << ((needle >> 4) & 7);
+    }
+
+    let (low_rows, high_rows) = unsafe {
+        (
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(low_rows.as_ptr().cast())),
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(high_rows.as_ptr().cast())),
+        )
+    };
+
+    // The bit of a high nibble within its row.
+    let bits = _mm256_setr_epi8(
+        1, 2, 4, 8, 16, 32, 64, -128, 1, 2, 4, 8, 16, 32, 64, -128, 1, 2, 4, 8, 16, 32, 64, -128,
+        1, 2, 4, 8, 16, 32, 64, -128,
+    );
+    let nibble_mask = _mm256_set1_epi8(0x0F);
+
+    let i = find_first_match!(array, |items| {
+        let low_nibbles = _mm256_and_si256(items, nibble_mask);
+        let high_nibbles = _mm256_and_si256(_mm256_srli_epi16(items, 4), nibble_mask);
+
+        let row = _mm256_blendv_epi8(
+            _mm256_shuffle_epi8(low_rows, low_nibbles),
+            _mm256_shuffle_epi8(high_rows, low_nibbles),
+            items,
+        );
+        let bit = _mm256_shuffle_epi8(bits, high_nibbles);
+
+        _mm256_cmpeq_epi8(_mm256_and_si256(row, bit), bit)
+    });
+
+    find_any_of_iter_u8(&array[i..], needles).map(|position| position + i)
+}
+
+/// Returns the position of the first occurrence of the non-empty `needle` in `haystack`.
+///
+/// Candidates are filtered by comparing 32 positions at once against the first byte of the needle and the byte at
+/// `probe`, so the whole needle only has to be compared where both match.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_subslice_avx(haystack: &[u8], needle: &[u8], probe: usize) -> Option<usize> {
+    const STEP: usize = 32;
+
+    if needle.len() > haystack.len() {
+        return None;
+    }
+
+    let first_mask = _mm256_set1_epi8(needle[0] as i8);
+    let probe_mask = _mm256_set1_epi8(needle[probe] as i8);
+
+    // The number of positions the needle can start at.
+    let candidates = haystack.len() - needle.len() + 1;
+    let mut i = 0;
+
+    let ptr = haystack.as_ptr();
+
+    unsafe {
+        while i + STEP <= candidates {
+            let first_items = _mm256_loadu_si256(ptr.add(i).cast());
+            let probe_items = _mm256_loadu_si256(ptr.add(i + probe).cast());
+
+            let compared = _mm256_and_si256(
+                _mm256_cmpeq_epi8(first_mask, first_items),
+                _mm256_cmpeq_epi8(probe_mask, probe_items),
+            );
+
+            let mut mask = _mm256_movemask_epi8(compared) as u32;
+            while mask != 0 {
+                let candidate = i + mask.trailing_zeros() as usize;
+                if haystack.get_unchecked(candidate..candidate + needle.len()) == needle {
+                    return Some(candidate);
+                }
+
+                mask &= mask - 1;
+            }
+
+            i += STEP;
+        }
+    }
+
+    find_subslice_iter_u8(&haystack[i..], needle, probe).map(|position| position + i)
+}
+
+/// Combines the byte masks of two consecutive comparisons into a mask of 64 bits.
+#[inline]
+#[target_feature(enable = "avx2")]
+fn movemask_64_avx(low: __m256i, high: __m256i) -> u64 {
+    let low = _mm256_movemask_epi8(low) as u32 as u64;
+    let high = _mm256_movemask_epi8(high) as u32 as u64;
+    low | (high << 32)
+}
+
+/// Returns the amount of occurrences of `needle` in `array`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn count_avx(array: &[u8], needle: u8) -> usize {
+    const STEP: usize = 128;
+
+    // Each iteration increments the 8 bit counters by up to 4, so they have to be flushed before they can overflow.
+    const FLUSH_INTERVAL: usize = u8::MAX as usize / 4 * STEP;
+
+    let needle_mask = _mm256_set1_epi8(needle as i8);
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut total = _mm256_setzero_si256();
+
+    unsafe {
+        while i < iterr {
+            let block_end = iterr.min(i + FLUSH_INTERVAL);
+            let mut counters = _mm256_setzero_si256();
+
+            while i < block_end {
+                let curr_items = _mm256_loadu_si256(ptr.cast());
+                let curr_items_2 = _mm256_loadu_si256(ptr.add(32).cast());
+                let curr_items_3 = _mm256_loadu_si256(ptr.add(64).cast());
+                let curr_items_4 = _mm256_loadu_si256(ptr.add(96).cast());
+
+                // Matching lanes are all ones (-1), so subtracting them increments the counter.
+                counters = _mm256_sub_epi8(counters, _mm256_cmpeq_epi8(curr_items, needle_mask));
+                counters = _mm256_sub_epi8(counters, _mm256_cmpeq_epi8(curr_items_2, needle_mask));
+                counters = _mm256_sub_epi8(counters, _mm256_cmpeq_epi8(curr_items_3, needle_mask));
+                counters = _mm256_sub_epi8(counters, _mm256_cmpeq_epi8(curr_items_4, needle_mask));
+
+                i += STEP;
+                ptr = ptr.add(STEP);
+            }
+
+            // Sum up groups of 8 counters into 64 bit lanes.
+            total = _mm256_add_epi64(total, _mm256_sad_epu8(counters, _mm256_setzero_si256()));
+        }
+    }
+
+    let simd_res = horizontal_sum_u64_avx(total) as usize;
+
+    let remainder = array[iterr..].iter().filter(|i| **i == needle).count();
+    remainder + simd_res
+}
+
 /// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn min_avx(array: &[u8]) -> Option<u8> {
@@ -258,6 +674,7 @@ pub fn min_avx(array: &[u8]) -> Option<u8> {
 }
 
 /// Returns the smallest item in the array, or `None` if the array was empty.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn max_avx(array: &[u8]) -> Option<u8> {
@@ -339,6 +756,446 @@ pub fn max_avx(array: &[u8]) -> Option<u8> {
     Some(max)
 }
 
+/// Returns the smallest and the largest item in the array, or `None` if the array was empty. Both are determined
+/// in a single pass, so the array is only loaded once.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn min_max_avx(array: &[u8]) -> Option<(u8, u8)> {
+    const STEP: usize = 128;
+
+    if array.is_empty() {
+        return None;
+    }
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut lmins1 = _mm256_set1_epi8(u8::MAX as i8);
+    let mut lmins2 = lmins1;
+    let mut lmax1 = _mm256_setzero_si256();
+    let mut lmax2 = lmax1;
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(32).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(64).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(96).cast());
+
+            lmins1 = _mm256_min_epu8(lmins1, _mm256_min_epu8(current, current2));
+            lmins2 = _mm256_min_epu8(lmins2, _mm256_min_epu8(current3, current4));
+            lmax1 = _mm256_max_epu8(lmax1, _mm256_max_epu8(current, current2));
+            lmax2 = _mm256_max_epu8(lmax2, _mm256_max_epu8(current3, current4));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let mut min = horizontal_min_u8_avx(_mm256_min_epu8(lmins1, lmins2));
+    let mut max = horizontal_max_u8_avx(_mm256_max_epu8(lmax1, lmax2));
+
+    for item in &array[iterr..] {
+        min = min.min(*item);
+        max = max.max(*item);
+    }
+
+    Some((min, max))
+}
+
+/// Returns the sum of all items in the array, or `0` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn sum_avx(array: &[u8]) -> u64 {
+    const STEP: usize = 128;
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let zero = _mm256_setzero_si256();
+    let mut sum1 = zero;
+    let mut sum2 = zero;
+    let mut sum3 = zero;
+    let mut sum4 = zero;
+
+    unsafe {
+        while i < iterr {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(32).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(64).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(96).cast());
+
+            // Sums up each group of 8 bytes into a 64 bit lane, which can't overflow.
+            sum1 = _mm256_add_epi64(sum1, _mm256_sad_epu8(current, zero));
+            sum2 = _mm256_add_epi64(sum2, _mm256_sad_epu8(current2, zero));
+            sum3 = _mm256_add_epi64(sum3, _mm256_sad_epu8(current3, zero));
+            sum4 = _mm256_add_epi64(sum4, _mm256_sad_epu8(current4, zero));
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    let s1 = _mm256_add_epi64(sum1, sum2);
+    let s2 = _mm256_add_epi64(sum3, sum4);
+    let sum = horizontal_sum_u64_avx(_mm256_add_epi64(s1, s2));
+
+    sum + array[iterr..].iter().map(|i| *i as u64).sum::<u64>()
+}
+
+/// Returns the index of the first smallest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn argmin_avx(array: &[u8]) -> Option<usize> {
+    arg_extreme_avx(array, false)
+}
+
+/// Returns the index of the first largest item in the array, or `None` if the array was empty.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn argmax_avx(array: &[u8]) -> Option<usize> {
+    arg_extreme_avx(array, true)
+}
+
+/// Finds the first minimum or maximum by keeping the extreme of every lane together with the index it was found at.
+///
+/// An 8 bit lane can only count 256 iterations, so the array is processed in chunks whose results are merged.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn arg_extreme_avx(array: &[u8], max: bool) -> Option<usize> {
+    const STEP: usize = 128;
+    const CHUNK: usize = 256 * STEP;
+
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+
+    // Nothing can beat this value, so the search can stop once it has been found.
+    let limit = if max { u8::MAX } else { u8::MIN };
+
+    let mut best: Option<(u8, usize)> = None;
+    let mut chunk_start = 0;
+
+    while chunk_start < iterr {
+        let chunk_end = (chunk_start + CHUNK).min(iterr);
+        let (value, index) = chunk_arg_extreme_avx(array, chunk_start, chunk_end, max);
+
+        // Earlier chunks win on equal values.
+        if best.is_none_or(|(best_value, _)| value != best_value && (value > best_value) == max) {
+            best = Some((value, index));
+        }
+
+        if value == limit {
+            return best.map(|(_, index)| index);
+        }
+
+        chunk_start = chunk_end;
+    }
+
+    for (index, value) in array.iter().enumerate().skip(iterr) {
+        if best.is_none_or(|(best_value, _)| *value != best_value && (*value > best_value) == max) {
+            best = Some((*value, index));
+        }
+    }
+
+    best.map(|(_, index)| index)
+}
+
+/// Returns the first extreme of `array[start..end]` and its index. The length of the range has to be a non zero
+/// multiple of 128 and at most 256 times that.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn chunk_arg_extreme_avx(array: &[u8], start: usize, end: usize, max: bool) -> (u8, usize) {
+    const STEP: usize = 128;
+
+    let mut i = start;
+
+    // Every lane stores the iteration its extreme has been found in, starting with the first one. This is correct
+    // if no item beats the initial value.
+    let mut iteration = _mm256_setzero_si256();
+    let one = _mm256_set1_epi8(1);
+
+    let mut iterations1 = iteration;
+    let mut iterations2 = iteration;
+    let mut iterations3 = iteration;
+    let mut iterations4 = iteration;
+
+    let init = _mm256_set1_epi8(if max { 0 } else { u8::MAX as i8 });
+    let mut extremes1 = init;
+    let mut extremes2 = init;
+    let mut extremes3 = init;
+    let mut extremes4 = init;
+
+    unsafe {
+        let mut ptr = array.as_ptr().add(start);
+
+        while i < end {
+            let current = _mm256_loadu_si256(ptr.cast());
+            let current2 = _mm256_loadu_si256(ptr.add(32).cast());
+            let current3 = _mm256_loadu_si256(ptr.add(64).cast());
+            let current4 = _mm256_loadu_si256(ptr.add(96).cast());
+
+            (extremes1, iterations1) =
+                update_extremes(extremes1, iterations1, current, iteration, max);
+            (extremes2, iterations2) =
+                update_extremes(extremes2, iterations2, current2, iteration, max);
+            (extremes3, iterations3) =
+                update_extremes(extremes3, iterations3, current3, iteration, max);
+            (extremes4, iterations4) =
+                update_extremes(extremes4, iterations4, current4, iteration, max);
+
+            iteration = _mm256_add_epi8(iteration, one);
+
+            i += STEP;
+            ptr = ptr.add(STEP);
+        }
+    }
+
+    // Safety: we can safely transmute a __m256i to [u8; 32]
+    let extremes: [[u8; 32]; 4] =
+        unsafe { transmute([extremes1, extremes2, extremes3, extremes4]) };
+    let iterations: [[u8; 32]; 4] =
+        unsafe { transmute([iterations1, iterations2, iterations3, iterations4]) };
+
+    let mut best = (if max { u8::MIN } else { u8::MAX }, usize::MAX);
+
+    for (vector, (extremes, iterations)) in extremes.iter().zip(&iterations).enumerate() {
+        for lane in 0..32 {
+            let value = extremes[lane];
+            let index = start + iterations[lane] as usize * STEP + vector * 32 + lane;
+
+            let better = if value == best.0 {
+                index < best.1
+            } else {
+                (value > best.0) == max
+            };
+
+            if better {
+                best = (value, index);
+            }
+        }
+    }
+
+    best
+}
+
+/// Keeps the extreme of `extremes` and `current` in every lane, together with the iteration it was found in. Equal
+/// items keep the earlier iteration.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+fn update_extremes(
+    extremes: __m256i,
+    iterations: __m256i,
+    current: __m256i,
+    iteration: __m256i,
+    max: bool,
+) -> (__m256i, __m256i) {
+    if max {
+        let keep = _mm256_cmpeq_epi8(_mm256_min_epu8(current, extremes), current);
+        (
+            _mm256_max_epu8(current, extremes),
+            _mm256_blendv_epi8(iteration, iterations, keep),
+        )
+    } else {
+        let keep = _mm256_cmpeq_epi8(_mm256_max_epu8(current, extremes), current);
+        (
+            _mm256_min_epu8(current, extremes),
+            _mm256_blendv_epi8(iteration, iterations, keep),
+        )
+    }
+}
+
+/// Returns the position of the first item that differs between `array` and `other`, or the length of the shorter
+/// one if it is a prefix of the longer one. Returns `None` if both are equal.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn mismatch_avx(array: &[u8], other: &[u8]) -> Option<usize> {
+    const STEP: usize = 128;
+
+    let len = array.len().min(other.len());
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let ptr = array.as_ptr();
+    let other_ptr = other.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let mut compared = [_mm256_setzero_si256(); 4];
+            for (j, compared) in compared.iter_mut().enumerate() {
+                let curr_items = _mm256_loadu_si256(ptr.add(i + j * 32).cast());
+                let other_items = _mm256_loadu_si256(other_ptr.add(i + j * 32).cast());
+                *compared = _mm256_cmpeq_epi8(curr_items, other_items);
+            }
+
+            let equal = _mm256_and_si256(
+                _mm256_and_si256(compared[0], compared[1]),
+                _mm256_and_si256(compared[2], compared[3]),
+            );
+
+            if _mm256_movemask_epi8(equal) != -1 {
+                for (j, compared) in compared.iter().enumerate() {
+                    let mask = !_mm256_movemask_epi8(*compared) as u32;
+                    if mask != 0 {
+                        return Some(mask.trailing_zeros() as usize + i + j * 32);
+                    }
+                }
+            }
+
+            i += STEP;
+        }
+    }
+
+    mismatch_iter_u8(&array[i..], &other[i..]).map(|position| position + i)
+}
+
+/// Returns a mask of the bytes of `items` that lie within `lo..=hi`, which must not be empty.
+#[inline]
+#[target_feature(enable = "avx2")]
+fn in_range_avx(items: __m256i, lo: __m256i, hi: __m256i) -> __m256i {
+    // Only items within the range are unchanged by clamping them, which compares unsigned.
+    _mm256_cmpeq_epi8(_mm256_min_epu8(_mm256_max_epu8(items, lo), hi), items)
+}
+
+/// Returns the amount of items within `lo..=hi`.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn count_in_range_avx(array: &[u8], lo: u8, hi: u8) -> usize {
+    const STEP: usize = 128;
+
+    // Each iteration increments the 8 bit counters by up to 4, so they have to be flushed before they can overflow.
+    const FLUSH_INTERVAL: usize = u8::MAX as usize / 4 * STEP;
+
+    if lo > hi {
+        return 0;
+    }
+
+    let lo_mask = _mm256_set1_epi8(lo as i8);
+    let hi_mask = _mm256_set1_epi8(hi as i8);
+    let len = array.len();
+    let m = len % STEP;
+    let iterr = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    let mut total = _mm256_setzero_si256();
+
+    unsafe {
+        while i < iterr {
+            let block_end = iterr.min(i + FLUSH_INTERVAL);
+            let mut counters = _mm256_setzero_si256();
+
+            while i < block_end {
+                let curr_items = _mm256_loadu_si256(ptr.cast());
+                let curr_items_2 = _mm256_loadu_si256(ptr.add(32).cast());
+                let curr_items_3 = _mm256_loadu_si256(ptr.add(64).cast());
+                let curr_items_4 = _mm256_loadu_si256(ptr.add(96).cast());
+
+                // Matching lanes are all ones (-1), so subtracting them increments the counter.
+                counters = _mm256_sub_epi8(counters, in_range_avx(curr_items, lo_mask, hi_mask));
+                counters = _mm256_sub_epi8(counters, in_range_avx(curr_items_2, lo_mask, hi_mask));
+                counters = _mm256_sub_epi8(counters, in_range_avx(curr_items_3, lo_mask, hi_mask));
+                counters = _mm256_sub_epi8(counters, in_range_avx(curr_items_4, lo_mask, hi_mask));
+
+                i += STEP;
+                ptr = ptr.add(STEP);
+            }
+
+            // Sum up groups of 8 counters into 64 bit lanes.
+            total = _mm256_add_epi64(total, _mm256_sad_epu8(counters, _mm256_setzero_si256()));
+        }
+    }
+
+    let simd_res = horizontal_sum_u64_avx(total) as usize;
+
+    simd_res + count_in_range_iter_u8(&array[iterr..], lo, hi)
+}
+
+/// Returns the position of the first item within `lo..=hi` if there is any.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn find_in_range_avx(array: &[u8], lo: u8, hi: u8) -> Option<usize> {
+    if lo > hi {
+        return None;
+    }
+
+    let lo_mask = _mm256_set1_epi8(lo as i8);
+    let hi_mask = _mm256_set1_epi8(hi as i8);
+
+    let i = find_first_match!(array, |items| in_range_avx(items, lo_mask, hi_mask));
+
+    find_in_range_iter_u8(&array[i..], lo, hi).map(|position| position + i)
+}
+
+/// Returns `true` if all items are `value`, which is the case for an empty array.
+#[inline]
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub fn all_eq_avx(array: &[u8], value: u8) -> bool {
+    const STEP: usize = 128;
+
+    let value_mask = _mm256_set1_epi8(value as i8);
+
+    let len = array.len();
+    let m = len % STEP;
+    let vectorized_part = len - m;
+    let mut i = 0;
+
+    let mut ptr = array.as_ptr();
+
+    unsafe {
+        while i < vectorized_part {
+            let curr_items = _mm256_loadu_si256(ptr.cast());
+            let curr_items_p1 = _mm256_loadu_si256(ptr.add(32).cast());
+            let curr_items_p2 = _mm256_loadu_si256(ptr.add(64).cast());
+            let curr_items_p3 = _mm256_loadu_si256(ptr.add(96).cast());
+
+            // Bits of items other than `value` remain set.
+            let differences = _mm256_or_si256(
+                _mm256_or_si256(
+                    _mm256_xor_si256(value_mask, curr_items),
+                    _mm256_xor_si256(value_mask, curr_items_p1),
+                ),
+                _mm256_or_si256(
+                    _mm256_xor_si256(value_mask, curr_items_p2),
+                    _mm256_xor_si256(value_mask, curr_items_p3),
+                ),
+            );
+
+            if _mm256_testz_si256(differences, differences) == 0 {
+                return false;
+            }
+
+            ptr = ptr.add(STEP);
+            i += STEP;
+        }
+    }
+
+    all_eq_iter_u8(&array[vectorized_part..], value)
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashSet;
@@ -348,6 +1205,7 @@ mod test {
     use rstest::rstest;
 
     use super::*;
+    use crate::original::array::{argmax_iter_u8, argmin_iter_u8, rfind_iter_u8, sum_iter_u8};
 
     fn random_array_with_count(
         rng: &mut impl Rng,
@@ -407,13 +1265,10 @@ mod test {
             })
             .collect();
 
-        match (value, index) {
-            (Some(val), Some(index)) => {
-                if index < len {
-                    vec[index] = val;
-                }
-            }
-            _ => (),
+        if let (Some(val), Some(index)) = (value, index)
+            && index < len
+        {
+            vec[index] = val;
         }
 
         vec
@@ -471,6 +1326,7 @@ mod test {
     #[case::test_simd_arbitrary(128, Some(102))]
     #[case::test_simd_arbitrary(128, Some(126))]
     #[case::test_simd_arbitrary(128, Some(127))]
+    #[case::test_simd_half_width(160, Some(140))]
     fn test_array_find(#[case] len: usize, #[case] index: Option<usize>) {
         let mut rng = StdRng::seed_from_u64(42);
 
@@ -488,7 +1344,7 @@ mod test {
     fn test_array_find_fuzzy() {
         let mut rng = StdRng::seed_from_u64(42);
 
-        for size in [32, 64, 127, 128, 256, 513] {
+        for size in [32, 64, 127, 128, 170, 256, 513] {
             let value = (rng.next_u32() % u8::MAX as u32) as u8;
             for index in 0..size {
                 for has in [true, false] {
@@ -505,6 +1361,159 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_array_find_all_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 63, 64, 65, 127, 128, 129, 170, 200, 513] {
+            for range in [8, 200] {
+                let vec: Vec<u8> = (0..size).map(|_| (rng.next_u32() % range) as u8).collect();
+
+                for value in 0..10 {
+                    let mut positions = Vec::new();
+                    let mut searched = 0;
+
+                    while let Some((start, mut mask)) =
+                        unsafe { find_all_avx(&vec[searched..], value) }
+                    {
+                        assert_ne!(mask, 0);
+
+                        let start = searched + start;
+                        while mask != 0 {
+                            positions.push(start + mask.trailing_zeros() as usize);
+                            mask &= mask - 1;
+                        }
+
+                        searched = (start + 64).min(size);
+                    }
+
+                    let expected: Vec<usize> = (0..size).filter(|i| vec[*i] == value).collect();
+                    assert_eq!(positions, expected, "{value} in {vec:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_find_any_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 31, 32, 33, 127, 128, 129, 170, 300, 513] {
+            let vec: Vec<u8> = (0..size).map(|_| rng.next_u32() as u8).collect();
+
+            for _ in 0..50 {
+                let [a, b, c, _] = rng.next_u32().to_le_bytes();
+
+                assert_eq!(
+                    unsafe { find_any2_avx(&vec, a, b) },
+                    find_any2_iter_u8(&vec, a, b)
+                );
+                assert_eq!(
+                    unsafe { find_any3_avx(&vec, a, b, c) },
+                    find_any3_iter_u8(&vec, a, b, c)
+                );
+
+                let count = rng.next_u32() as usize % 8;
+                let needles: Vec<u8> = (0..count).map(|_| rng.next_u32() as u8).collect();
+                assert_eq!(
+                    unsafe { find_any_of_avx(&vec, &needles) },
+                    find_any_of_iter_u8(&vec, &needles),
+                    "{needles:?} in {vec:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_find_any_of_every_byte() {
+        let array: Vec<u8> = (0..=255).collect();
+
+        for needle in 0..=255u8 {
+            let position = Some(needle as usize);
+            assert_eq!(unsafe { find_any_of_avx(&array, &[needle]) }, position);
+            // Bytes sharing a nibble with the needle must not match.
+            let others = [needle ^ 0x80, needle ^ 0x08, needle ^ 0x01, needle ^ 0x10];
+            let position = others.iter().map(|i| *i as usize).min();
+            assert_eq!(unsafe { find_any_of_avx(&array, &others) }, position);
+        }
+
+        assert_eq!(unsafe { find_any_of_avx(&array, &[]) }, None);
+    }
+
+    #[test]
+    fn test_array_find_subslice_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 2, 31, 32, 33, 63, 64, 65, 127, 300, 513] {
+            let vec: Vec<u8> = (0..size).map(|_| (rng.next_u32() % 3) as u8).collect();
+
+            for needle_len in [1, 2, 3, 4, 7, 16, 33] {
+                for _ in 0..10 {
+                    let needle: Vec<u8> = (0..needle_len)
+                        .map(|_| (rng.next_u32() % 3) as u8)
+                        .collect();
+                    let probe = rng.next_u32() as usize % needle_len;
+
+                    assert_eq!(
+                        unsafe { find_subslice_avx(&vec, &needle, probe) },
+                        vec.windows(needle_len).position(|window| window == needle),
+                        "{needle:?} in {vec:?}"
+                    );
+                    assert_eq!(
+                        find_subslice_iter_u8(&vec, &needle, probe),
+                        vec.windows(needle_len).position(|window| window == needle)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_rfind_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 31, 32, 33, 127, 128, 129, 170, 256, 513] {
+            // Small values, so most needles occur several times.
+            let vec: Vec<u8> = (0..size).map(|_| (rng.next_u32() % 64) as u8).collect();
+
+            for value in 0..70 {
+                let simd_result = unsafe { rfind_avx(&vec, value) };
+                assert_eq!(
+                    simd_result,
+                    rfind_iter_u8(&vec, value),
+                    "{value} in {vec:?}"
+                );
+            }
+
+            for index in 0..size {
+                let vec = random_array_with_value(&mut rng, size, Some(70), Some(index));
+                assert_eq!(unsafe { rfind_avx(&vec, 70) }, Some(index));
+            }
+        }
+    }
+
+    #[rstest]
+    // (len, count)
+    #[case(0, 0)]
+    #[case(5, 3)]
+    #[case(127, 0)]
+    #[case(128, 1)]
+    #[case(128, 128)]
+    #[case(129, 64)]
+    #[case(513, 200)]
+    // Exceeds the flush interval of the 8 bit counters.
+    #[case(40_000, 40_000)]
+    #[case(40_000, 33_333)]
+    fn test_array_count(#[case] len: usize, #[case] count: usize) {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let needle = (rng.next_u32() % u8::MAX as u32) as u8;
+        let vec = random_array_with_count(&mut rng, len, count, needle);
+
+        let simd_count = unsafe { count_avx(&vec, needle) };
+        assert_eq!(simd_count, count);
+    }
+
     #[test]
     fn test_array_min_max_fuzzy() {
         let mut rng = StdRng::seed_from_u64(42);
@@ -521,4 +1530,126 @@ mod test {
             assert_eq!(simd_max, real_max);
         }
     }
+
+    #[test]
+    fn test_array_min_max_single_pass_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 127, 128, 129, 256, 513, 1024, 6256] {
+            let vec: Vec<u8> = (0..len).map(|_| rng.next_u32() as u8).collect();
+
+            let expected = vec.iter().min().copied().zip(vec.iter().max().copied());
+            assert_eq!(unsafe { min_max_avx(&vec) }, expected);
+        }
+
+        for vec in [vec![0u8; 200], vec![u8::MAX; 200]] {
+            assert_eq!(unsafe { min_max_avx(&vec) }, Some((vec[0], vec[0])));
+        }
+    }
+
+    #[test]
+    fn test_array_arg_min_max_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 7, 31, 127, 128, 129, 513, 6256, 40_000, 70_000] {
+            for modulo in [3, 200, 256] {
+                let vec: Vec<u8> = (0..len).map(|_| (rng.next_u32() % modulo) as u8).collect();
+
+                assert_eq!(unsafe { argmin_avx(&vec) }, argmin_iter_u8(&vec));
+                assert_eq!(unsafe { argmax_avx(&vec) }, argmax_iter_u8(&vec));
+            }
+        }
+
+        // Extremes only in later chunks.
+        let mut vec = vec![100u8; 70_000];
+        vec[69_000] = 50;
+        vec[69_001] = 50;
+        vec[40_000] = 150;
+        assert_eq!(unsafe { argmin_avx(&vec) }, Some(69_000));
+        assert_eq!(unsafe { argmax_avx(&vec) }, Some(40_000));
+
+        for value in [0, u8::MAX] {
+            let vec = vec![value; 1000];
+            assert_eq!(unsafe { argmin_avx(&vec) }, Some(0));
+            assert_eq!(unsafe { argmax_avx(&vec) }, Some(0));
+        }
+    }
+
+    #[test]
+    fn test_array_sum_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for len in [0, 1, 31, 127, 128, 129, 513, 1024, 70_000] {
+            let vec: Vec<u8> = (0..len).map(|_| rng.next_u32() as u8).collect();
+            assert_eq!(unsafe { sum_avx(&vec) }, sum_iter_u8(&vec));
+        }
+
+        let vec = vec![u8::MAX; 70_000];
+        assert_eq!(unsafe { sum_avx(&vec) }, 255 * 70_000);
+    }
+
+    #[test]
+    fn test_array_mismatch_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 7, 8, 31, 32, 33, 127, 128, 129, 300, 513] {
+            let vec: Vec<u8> = (0..size).map(|_| rng.next_u32() as u8).collect();
+
+            assert_eq!(unsafe { mismatch_avx(&vec, &vec) }, None);
+
+            for index in 0..size {
+                let mut other = vec.clone();
+                other[index] ^= 1;
+                assert_eq!(unsafe { mismatch_avx(&vec, &other) }, Some(index));
+                assert_eq!(unsafe { mismatch_avx(&other[..index], &vec) }, Some(index));
+                assert_eq!(unsafe { mismatch_avx(&vec, &vec[..index]) }, Some(index));
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_all_eq_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 31, 32, 33, 127, 128, 129, 300, 513] {
+            let value = rng.next_u32() as u8;
+            let vec = vec![value; size];
+
+            assert!(unsafe { all_eq_avx(&vec, value) });
+            assert_eq!(unsafe { all_eq_avx(&vec, !value) }, size == 0);
+
+            for index in 0..size {
+                let mut other = vec.clone();
+                other[index] ^= 0x80;
+                assert!(!unsafe { all_eq_avx(&other, value) });
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_in_range_fuzzy() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for size in [0, 1, 31, 32, 33, 127, 128, 129, 300, 513, 40_000] {
+            let vec: Vec<u8> = (0..size).map(|_| rng.next_u32() as u8).collect();
+
+            for _ in 0..20 {
+                // Half of the random bounds have the top bit set, which needs unsigned comparisons.
+                let [lo, hi, _, _] = rng.next_u32().to_le_bytes();
+
+                assert_eq!(
+                    unsafe { count_in_range_avx(&vec, lo, hi) },
+                    count_in_range_iter_u8(&vec, lo, hi),
+                    "{lo}..={hi}"
+                );
+                assert_eq!(
+                    unsafe { find_in_range_avx(&vec, lo, hi) },
+                    find_in_range_iter_u8(&vec, lo, hi),
+                    "{lo}..={hi}"
+                );
+            }
+
+            assert_eq!(unsafe { count_in_range_avx(&vec, 0, u8::MAX) }, size);
+        }
+    }
 }
@@ -0,0 +1,626 @@
+use crate::dispatch::{
+    Backend, Element, F32Kernels, F64Kernels, I8Kernels, I16Kernels, I32Kernels, I64Kernels,
+    Kernels, Operation, U8Kernels, U16Kernels, U32Kernels, U64Kernels, threshold,
+};
+use crate::float::{NanMode, SumMode};
+use crate::original::array::*;
+use crate::x86_64::array::{
+    avx512, f32_impl, f64_impl, i8_impl, i16_impl, i32_impl, i64_impl, sse, u8_impl, u16_impl,
+    u32_impl, u64_impl,
+};
+
+/// Wraps a `#[target_feature]` kernel into a safe function that can be stored in a kernel table.
+/// Arrays shorter than the [`threshold`] of the operation are passed to `$fallback` instead, as the SIMD overhead
+/// outweighs its gains for them.
+///
+/// The generated function must only be reachable from a table that gets selected after the required CPU
+/// features have been detected.
+macro_rules! kernel {
+    ($name:ident, $kernel:path, $fallback:path, ($element:ident, $operation:ident), [$ty:ty] $(, $arg:ident: $arg_ty:ty)* => $ret:ty) => {
+        #[inline]
+        fn $name(array: &[$ty] $(, $arg: $arg_ty)*) -> $ret {
+            if array.len() >= threshold(Element::$element, Operation::$operation) {
+                // Safety: The table containing this kernel is only selected if the CPU supports the required features.
+                unsafe { $kernel(array $(, $arg)*) }
+            } else {
+                $fallback(array $(, $arg)*)
+            }
+        }
+    };
+}
+
+/// Returns the backend whose vectorized kernel the table of `backend` uses for `operation` on `element`.
+///
+/// Not every backend provides kernels for all element types. Tables fall back to the kernels of a smaller
+/// instruction set they imply, or to the original implementations if there are none.
+pub(crate) fn kernel_backend(backend: Backend, element: Element, operation: Operation) -> Backend {
+    // SSE and AVX-512 kernels only exist for the original operations of these element types.
+    let native = matches!(element, Element::U8 | Element::U32 | Element::F32)
+        && matches!(
+            operation,
+            Operation::Min
+                | Operation::Max
+                | Operation::Find
+                | Operation::Contains
+                | Operation::Count
+        );
+
+    match backend {
+        Backend::Sse if !native => Backend::Scalar,
+        Backend::Avx512 if !native => Backend::Avx2,
+        _ => backend,
+    }
+}
+
+kernel!(min_u8_sse, sse::u8_impl::min_sse, min_iter_u8, (U8, Min), [u8] => Option<u8>);
+kernel!(max_u8_sse, sse::u8_impl::max_sse, max_iter_u8, (U8, Max), [u8] => Option<u8>);
+kernel!(find_u8_sse, sse::u8_impl::find_sse, find_iter_u8, (U8, Find), [u8], needle: u8 => Option<usize>);
+kernel!(contains_u8_sse, sse::u8_impl::contains_sse, contains_iter_u8, (U8, Contains), [u8], needle: u8 => bool);
+kernel!(count_u8_sse, sse::u8_impl::count_sse, count_iter_u8, (U8, Count), [u8], element: u8 => usize);
+
+kernel!(min_u32_sse, sse::u32_impl::min_sse, min_iter_u32, (U32, Min), [u32] => Option<u32>);
+kernel!(max_u32_sse, sse::u32_impl::max_sse, max_iter_u32, (U32, Max), [u32] => Option<u32>);
+kernel!(find_u32_sse, sse::u32_impl::find_sse, find_iter_u32, (U32, Find), [u32], needle: u32 => Option<usize>);
+kernel!(contains_u32_sse, sse::u32_impl::contains_sse, contains_iter_u32, (U32, Contains), [u32], needle: u32 => bool);
+kernel!(count_u32_sse, sse::u32_impl::count_sse, count_iter_u32, (U32, Count), [u32], element: u32 => usize);
+
+kernel!(min_f32_sse, sse::f32_impl::min_sse, min_iter_f32, (F32, Min), [f32], mode: NanMode => Option<f32>);
+kernel!(max_f32_sse, sse::f32_impl::max_sse, max_iter_f32, (F32, Max), [f32], mode: NanMode => Option<f32>);
+
+/// Kernels requiring SSE4.1.
+pub(crate) static SSE: Kernels = Kernels {
+    backend: Backend::Sse,
+    u8: U8Kernels {
+        min: min_u8_sse,
+        max: max_u8_sse,
+        min_max: min_max_iter_u8,
+        find: find_u8_sse,
+        rfind: rfind_iter_u8,
+        mismatch: mismatch_iter_u8,
+        all_eq: all_eq_iter_u8,
+        count_in_range: count_in_range_iter_u8,
+        find_in_range: find_in_range_iter_u8,
+        find_all: find_all_iter_u8,
+        find_any2: find_any2_iter_u8,
+        find_any3: find_any3_iter_u8,
+        find_any_of: find_any_of_iter_u8,
+        find_subslice: find_subslice_iter_u8,
+        contains: contains_u8_sse,
+        count: count_u8_sse,
+        argmin: argmin_iter_u8,
+        argmax: argmax_iter_u8,
+        sum: sum_iter_u8,
+    },
+    u16: U16Kernels {
+        min: min_iter_u16,
+        max: max_iter_u16,
+        min_max: min_max_iter_u16,
+        find: find_iter_u16,
+        contains: contains_iter_u16,
+        count: count_iter_u16,
+    },
+    u32: U32Kernels {
+        min: min_u32_sse,
+        max: max_u32_sse,
+        min_max: min_max_iter_u32,
+        find: find_u32_sse,
+        rfind: rfind_iter_u32,
+        mismatch: mismatch_iter_u32,
+        all_eq: all_eq_iter_u32,
+        count_in_range: count_in_range_iter_u32,
+        find_in_range: find_in_range_iter_u32,
+        find_all: find_all_iter_u32,
+        contains: contains_u32_sse,
+        count: count_u32_sse,
+        argmin: argmin_iter_u32,
+        argmax: argmax_iter_u32,
+        sum: sum_iter_u32,
+    },
+    u64: U64Kernels {
+        min: min_iter_u64,
+        max: max_iter_u64,
+        min_max: min_max_iter_u64,
+        find: find_iter_u64,
+        contains: contains_iter_u64,
+        count: count_iter_u64,
+    },
+    i8: I8Kernels {
+        min: min_iter_i8,
+        max: max_iter_i8,
+        min_max: min_max_iter_i8,
+        find: find_iter_i8,
+        contains: contains_iter_i8,
+        count: count_iter_i8,
+    },
+    i16: I16Kernels {
+        min: min_iter_i16,
+        max: max_iter_i16,
+        min_max: min_max_iter_i16,
+        find: find_iter_i16,
+        contains: contains_iter_i16,
+        count: count_iter_i16,
+    },
+    i32: I32Kernels {
+        min: min_iter_i32,
+        max: max_iter_i32,
+        min_max: min_max_iter_i32,
+        find: find_iter_i32,
+        contains: contains_iter_i32,
+        count: count_iter_i32,
+    },
+    i64: I64Kernels {
+        min: min_iter_i64,
+        max: max_iter_i64,
+        min_max: min_max_iter_i64,
+        find: find_iter_i64,
+        contains: contains_iter_i64,
+        count: count_iter_i64,
+    },
+    f32: F32Kernels {
+        min: min_f32_sse,
+        max: max_f32_sse,
+        min_max: min_max_iter_f32,
+        argmin: argmin_iter_f32,
+        argmax: argmax_iter_f32,
+        sum: sum_iter_f32,
+        mismatch: mismatch_iter_f32,
+        count_in_range: count_in_range_iter_f32,
+        find_in_range: find_in_range_iter_f32,
+    },
+    f64: F64Kernels {
+        min: min_iter_f64,
+        max: max_iter_f64,
+        min_max: min_max_iter_f64,
+        find: find_iter_f64,
+        contains: contains_iter_f64,
+        count: count_iter_f64,
+        sum: sum_iter_f64,
+    },
+};
+
+kernel!(min_u8_avx2, u8_impl::min_avx, min_iter_u8, (U8, Min), [u8] => Option<u8>);
+kernel!(max_u8_avx2, u8_impl::max_avx, max_iter_u8, (U8, Max), [u8] => Option<u8>);
+kernel!(min_max_u8_avx2, u8_impl::min_max_avx, min_max_iter_u8, (U8, MinMax), [u8] => Option<(u8, u8)>);
+kernel!(find_u8_avx2, u8_impl::find_avx, find_iter_u8, (U8, Find), [u8], needle: u8 => Option<usize>);
+kernel!(rfind_u8_avx2, u8_impl::rfind_avx, rfind_iter_u8, (U8, RFind), [u8], needle: u8 => Option<usize>);
+kernel!(find_all_u8_avx2, u8_impl::find_all_avx, find_all_iter_u8, (U8, FindAll), [u8], needle: u8 => Option<(usize, u64)>);
+kernel!(mismatch_u8_avx2, u8_impl::mismatch_avx, mismatch_iter_u8, (U8, Mismatch), [u8], other: &[u8] => Option<usize>);
+kernel!(all_eq_u8_avx2, u8_impl::all_eq_avx, all_eq_iter_u8, (U8, AllEq), [u8], value: u8 => bool);
+kernel!(count_in_range_u8_avx2, u8_impl::count_in_range_avx, count_in_range_iter_u8, (U8, CountInRange), [u8], lo: u8, hi: u8 => usize);
+kernel!(find_in_range_u8_avx2, u8_impl::find_in_range_avx, find_in_range_iter_u8, (U8, FindInRange), [u8], lo: u8, hi: u8 => Option<usize>);
+kernel!(find_any2_u8_avx2, u8_impl::find_any2_avx, find_any2_iter_u8, (U8, FindAny2), [u8], a: u8, b: u8 => Option<usize>);
+kernel!(find_any3_u8_avx2, u8_impl::find_any3_avx, find_any3_iter_u8, (U8, FindAny3), [u8], a: u8, b: u8, c: u8 => Option<usize>);
+kernel!(find_any_of_u8_avx2, u8_impl::find_any_of_avx, find_any_of_iter_u8, (U8, FindAnyOf), [u8], needles: &[u8] => Option<usize>);
+kernel!(find_subslice_u8_avx2, u8_impl::find_subslice_avx, find_subslice_iter_u8, (U8, FindSubslice), [u8], needle: &[u8], probe: usize => Option<usize>);
+kernel!(contains_u8_avx2, u8_impl::contains_avx, contains_iter_u8, (U8, Contains), [u8], needle: u8 => bool);
+kernel!(count_u8_avx2, u8_impl::count_avx, count_iter_u8, (U8, Count), [u8], element: u8 => usize);
+
+kernel!(min_u32_avx2, u32_impl::min_avx, min_iter_u32, (U32, Min), [u32] => Option<u32>);
+kernel!(max_u32_avx2, u32_impl::max_avx, max_iter_u32, (U32, Max), [u32] => Option<u32>);
+kernel!(min_max_u32_avx2, u32_impl::min_max_avx, min_max_iter_u32, (U32, MinMax), [u32] => Option<(u32, u32)>);
+kernel!(find_u32_avx2, u32_impl::find_avx, find_iter_u32, (U32, Find), [u32], needle: u32 => Option<usize>);
+kernel!(rfind_u32_avx2, u32_impl::rfind_avx, rfind_iter_u32, (U32, RFind), [u32], needle: u32 => Option<usize>);
+kernel!(find_all_u32_avx2, u32_impl::find_all_avx, find_all_iter_u32, (U32, FindAll), [u32], needle: u32 => Option<(usize, u64)>);
+kernel!(mismatch_u32_avx2, u32_impl::mismatch_avx, mismatch_iter_u32, (U32, Mismatch), [u32], other: &[u32] => Option<usize>);
+kernel!(all_eq_u32_avx2, u32_impl::all_eq_avx, all_eq_iter_u32, (U32, AllEq), [u32], value: u32 => bool);
+kernel!(count_in_range_u32_avx2, u32_impl::count_in_range_avx, count_in_range_iter_u32, (U32, CountInRange), [u32], lo: u32, hi: u32 => usize);
+kernel!(find_in_range_u32_avx2, u32_impl::find_in_range_avx, find_in_range_iter_u32, (U32, FindInRange), [u32], lo: u32, hi: u32 => Option<usize>);
+kernel!(contains_u32_avx2, u32_impl::contains_avx, contains_iter_u32, (U32, Contains), [u32], needle: u32 => bool);
+kernel!(count_u32_avx2, u32_impl::count_avx, count_iter_u32, (U32, Count), [u32], element: u32 => usize);
+
+kernel!(argmin_f32_avx2, f32_impl::argmin_avx, argmin_iter_f32, (F32, ArgMin), [f32] => Option<usize>);
+kernel!(argmax_f32_avx2, f32_impl::argmax_avx, argmax_iter_f32, (F32, ArgMax), [f32] => Option<usize>);
+kernel!(sum_f32_avx2, f32_impl::sum_avx, sum_iter_f32, (F32, Sum), [f32], mode: SumMode => f32);
+kernel!(mismatch_f32_avx2, f32_impl::mismatch_avx, mismatch_iter_f32, (F32, Mismatch), [f32], other: &[f32] => Option<usize>);
+kernel!(count_in_range_f32_avx2, f32_impl::count_in_range_avx, count_in_range_iter_f32, (F32, CountInRange), [f32], lo: f32, hi: f32 => usize);
+kernel!(find_in_range_f32_avx2, f32_impl::find_in_range_avx, find_in_range_iter_f32, (F32, FindInRange), [f32], lo: f32, hi: f32 => Option<usize>);
+
+kernel!(min_f64_avx2, f64_impl::min_avx, min_iter_f64, (F64, Min), [f64] => Option<f64>);
+kernel!(max_f64_avx2, f64_impl::max_avx, max_iter_f64, (F64, Max), [f64] => Option<f64>);
+kernel!(min_max_f64_avx2, f64_impl::min_max_avx, min_max_iter_f64, (F64, MinMax), [f64] => Option<(f64, f64)>);
+kernel!(find_f64_avx2, f64_impl::find_avx, find_iter_f64, (F64, Find), [f64], needle: f64 => Option<usize>);
+kernel!(contains_f64_avx2, f64_impl::contains_avx, contains_iter_f64, (F64, Contains), [f64], needle: f64 => bool);
+kernel!(count_f64_avx2, f64_impl::count_avx, count_iter_f64, (F64, Count), [f64], element: f64 => usize);
+kernel!(sum_f64_avx2, f64_impl::sum_avx, sum_iter_f64, (F64, Sum), [f64] => f64);
+
+kernel!(argmin_u8_avx2, u8_impl::argmin_avx, argmin_iter_u8, (U8, ArgMin), [u8] => Option<usize>);
+kernel!(argmax_u8_avx2, u8_impl::argmax_avx, argmax_iter_u8, (U8, ArgMax), [u8] => Option<usize>);
+kernel!(sum_u8_avx2, u8_impl::sum_avx, sum_iter_u8, (U8, Sum), [u8] => u64);
+
+kernel!(min_u16_avx2, u16_impl::min_avx, min_iter_u16, (U16, Min), [u16] => Option<u16>);
+kernel!(max_u16_avx2, u16_impl::max_avx, max_iter_u16, (U16, Max), [u16] => Option<u16>);
+kernel!(min_max_u16_avx2, u16_impl::min_max_avx, min_max_iter_u16, (U16, MinMax), [u16] => Option<(u16, u16)>);
+kernel!(find_u16_avx2, u16_impl::find_avx, find_iter_u16, (U16, Find), [u16], needle: u16 => Option<usize>);
+kernel!(contains_u16_avx2, u16_impl::contains_avx, contains_iter_u16, (U16, Contains), [u16], needle: u16 => bool);
+kernel!(count_u16_avx2, u16_impl::count_avx, count_iter_u16, (U16, Count), [u16], element: u16 => usize);
+
+kernel!(argmin_u32_avx2, u32_impl::argmin_avx, argmin_iter_u32, (U32, ArgMin), [u32] => Option<usize>);
+kernel!(argmax_u32_avx2, u32_impl::argmax_avx, argmax_iter_u32, (U32, ArgMax), [u32] => Option<usize>);
+kernel!(sum_u32_avx2, u32_impl::sum_avx, sum_iter_u32, (U32, Sum), [u32] => u64);
+
+kernel!(min_u64_avx2, u64_impl::min_avx, min_iter_u64, (U64, Min), [u64] => Option<u64>);
+kernel!(max_u64_avx2, u64_impl::max_avx, max_iter_u64, (U64, Max), [u64] => Option<u64>);
+kernel!(min_max_u64_avx2, u64_impl::min_max_avx, min_max_iter_u64, (U64, MinMax), [u64] => Option<(u64, u64)>);
+kernel!(find_u64_avx2, u64_impl::find_avx, find_iter_u64, (U64, Find), [u64], needle: u64 => Option<usize>);
+kernel!(contains_u64_avx2, u64_impl::contains_avx, contains_iter_u64, (U64, Contains), [u64], needle: u64 => bool);
+kernel!(count_u64_avx2, u64_impl::count_avx, count_iter_u64, (U64, Count), [u64], element: u64 => usize);
+
+kernel!(min_i8_avx2, i8_impl::min_avx, min_iter_i8, (I8, Min), [i8] => Option<i8>);
+kernel!(max_i8_avx2, i8_impl::max_avx, max_iter_i8, (I8, Max), [i8] => Option<i8>);
+kernel!(min_max_i8_avx2, i8_impl::min_max_avx, min_max_iter_i8, (I8, MinMax), [i8] => Option<(i8, i8)>);
+kernel!(find_i8_avx2, i8_impl::find_avx, find_iter_i8, (I8, Find), [i8], needle: i8 => Option<usize>);
+kernel!(contains_i8_avx2, i8_impl::contains_avx, contains_iter_i8, (I8, Contains), [i8], needle: i8 => bool);
+kernel!(count_i8_avx2, i8_impl::count_avx, count_iter_i8, (I8, Count), [i8], element: i8 => usize);
+
+kernel!(min_i16_avx2, i16_impl::min_avx, min_iter_i16, (I16, Min), [i16] => Option<i16>);
+kernel!(max_i16_avx2, i16_impl::max_avx, max_iter_i16, (I16, Max), [i16] => Option<i16>);
+kernel!(min_max_i16_avx2, i16_impl::min_max_avx, min_max_iter_i16, (I16, MinMax), [i16] => Option<(i16, i16)>);
+kernel!(find_i16_avx2, i16_impl::find_avx, find_iter_i16, (I16, Find), [i16], needle: i16 => Option<usize>);
+kernel!(contains_i16_avx2, i16_impl::contains_avx, contains_iter_i16, (I16, Contains), [i16], needle: i16 => bool);
+kernel!(count_i16_avx2, i16_impl::count_avx, count_iter_i16, (I16, Count), [i16], element: i16 => usize);
+
+kernel!(min_i32_avx2, i32_impl::min_avx, min_iter_i32, (I32, Min), [i32] => Option<i32>);
+kernel!(max_i32_avx2, i32_impl::max_avx, max_iter_i32, (I32, Max), [i32] => Option<i32>);
+kernel!(min_max_i32_avx2, i32_impl::min_max_avx, min_max_iter_i32, (I32, MinMax), [i32] => Option<(i32, i32)>);
+kernel!(find_i32_avx2, i32_impl::find_avx, find_iter_i32, (I32, Find), [i32], needle: i32 => Option<usize>);
+kernel!(contains_i32_avx2, i32_impl::contains_avx, contains_iter_i32, (I32, Contains), [i32], needle: i32 => bool);
+kernel!(count_i32_avx2, i32_impl::count_avx, count_iter_i32, (I32, Count), [i32], element: i32 => usize);
+
+kernel!(min_i64_avx2, i64_impl::min_avx, min_iter_i64, (I64, Min), [i64] => Option<i64>);
+kernel!(max_i64_avx2, i64_impl::max_avx, max_iter_i64, (I64, Max), [i64] => Option<i64>);
+kernel!(min_max_i64_avx2, i64_impl::min_max_avx, min_max_iter_i64, (I64, MinMax), [i64] => Option<(i64, i64)>);
+kernel!(find_i64_avx2, i64_impl::find_avx, find_iter_i64, (I64, Find), [i64], needle: i64 => Option<usize>);
+kernel!(contains_i64_avx2, i64_impl::contains_avx, contains_iter_i64, (I64, Contains), [i64], needle: i64 => bool);
+kernel!(count_i64_avx2, i64_impl::count_avx, count_iter_i64, (I64, Count), [i64], element: i64 => usize);
+
+kernel!(min_f32_avx2, f32_impl::min_avx, min_iter_f32, (F32, Min), [f32], mode: NanMode => Option<f32>);
+kernel!(max_f32_avx2, f32_impl::max_avx, max_iter_f32, (F32, Max), [f32], mode: NanMode => Option<f32>);
+kernel!(min_max_f32_avx2, f32_impl::min_max_avx, min_max_iter_f32, (F32, MinMax), [f32] => Option<(f32, f32)>);
+
+/// Kernels requiring AVX2.
+pub(crate) static AVX2: Kernels = Kernels {
+    backend: Backend::Avx2,
+    u8: U8Kernels {
+        min: min_u8_avx2,
+        max: max_u8_avx2,
+        min_max: min_max_u8_avx2,
+        find: find_u8_avx2,
+        rfind: rfind_u8_avx2,
+        mismatch: mismatch_u8_avx2,
+        all_eq: all_eq_u8_avx2,
+        count_in_range: count_in_range_u8_avx2,
+        find_in_range: find_in_range_u8_avx2,
+        find_all: find_all_u8_avx2,
+        find_any2: find_any2_u8_avx2,
+        find_any3: find_any3_u8_avx2,
+        find_any_of: find_any_of_u8_avx2,
+        find_subslice: find_subslice_u8_avx2,
+        contains: contains_u8_avx2,
+        count: count_u8_avx2,
+        argmin: argmin_u8_avx2,
+        argmax: argmax_u8_avx2,
+        sum: sum_u8_avx2,
+    },
+    u16: U16Kernels {
+        min: min_u16_avx2,
+        max: max_u16_avx2,
+        min_max: min_max_u16_avx2,
+        find: find_u16_avx2,
+        contains: contains_u16_avx2,
+        count: count_u16_avx2,
+    },
+    u32: U32Kernels {
+        min: min_u32_avx2,
+        max: max_u32_avx2,
+        min_max: min_max_u32_avx2,
+        find: find_u32_avx2,
+        rfind: rfind_u32_avx2,
+        mismatch: mismatch_u32_avx2,
+        all_eq: all_eq_u32_avx2,
+        count_in_range: count_in_range_u32_avx2,
+        find_in_range: find_in_range_u32_avx2,
+        find_all: find_all_u32_avx2,
+        contains: contains_u32_avx2,
+        count: count_u32_avx2,
+        argmin: argmin_u32_avx2,
+        argmax: argmax_u32_avx2,
+        sum: sum_u32_avx2,
+    },
+    u64: U64Kernels {
+        min: min_u64_avx2,
+        max: max_u64_avx2,
+        min_max: min_max_u64_avx2,
+        find: find_u64_avx2,
+        contains: contains_u64_avx2,
+        count: count_u64_avx2,
+    },
+    i8: I8Kernels {
+        min: min_i8_avx2,
+        max: max_i8_avx2,
+        min_max: min_max_i8_avx2,
+        find: find_i8_avx2,
+        contains: contains_i8_avx2,
+        count: count_i8_avx2,
+    },
+    i16: I16Kernels {
+        min: min_i16_avx2,
+        max: max_i16_avx2,
+        min_max: min_max_i16_avx2,
+        find: find_i16_avx2,
+        contains: contains_i16_avx2,
+        count: count_i16_avx2,
+    },
+    i32: I32Kernels {
+        min: min_i32_avx2,
+        max: max_i32_avx2,
+        min_max: min_max_i32_avx2,
+        find: find_i32_avx2,
+        contains: contains_i32_avx2,
+        count: count_i32_avx2,
+    },
+    i64: I64Kernels {
+        min: min_i64_avx2,
+        max: max_i64_avx2,
+        min_max: min_max_i64_avx2,
+        find: find_i64_avx2,
+        contains: contains_i64_avx2,
+        count: count_i64_avx2,
+    },
+    f32: F32Kernels {
+        min: min_f32_avx2,
+        max: max_f32_avx2,
+        min_max: min_max_f32_avx2,
+        argmin: argmin_f32_avx2,
+        argmax: argmax_f32_avx2,
+        sum: sum_f32_avx2,
+        mismatch: mismatch_f32_avx2,
+        count_in_range: count_in_range_f32_avx2,
+        find_in_range: find_in_range_f32_avx2,
+    },
+    f64: F64Kernels {
+        min: min_f64_avx2,
+        max: max_f64_avx2,
+        min_max: min_max_f64_avx2,
+        find: find_f64_avx2,
+        contains: contains_f64_avx2,
+        count: count_f64_avx2,
+        sum: sum_f64_avx2,
+    },
+};
+
+kernel!(min_u8_avx512, avx512::u8_impl::min_avx512, min_iter_u8, (U8, Min), [u8] => Option<u8>);
+kernel!(max_u8_avx512, avx512::u8_impl::max_avx512, max_iter_u8, (U8, Max), [u8] => Option<u8>);
+kernel!(find_u8_avx512, avx512::u8_impl::find_avx512, find_iter_u8, (U8, Find), [u8], needle: u8 => Option<usize>);
+kernel!(contains_u8_avx512, avx512::u8_impl::contains_avx512, contains_iter_u8, (U8, Contains), [u8], needle: u8 => bool);
+kernel!(count_u8_avx512, avx512::u8_impl::count_avx512, count_iter_u8, (U8, Count), [u8], element: u8 => usize);
+
+kernel!(min_u32_avx512, avx512::u32_impl::min_avx512, min_iter_u32, (U32, Min), [u32] => Option<u32>);
+kernel!(max_u32_avx512, avx512::u32_impl::max_avx512, max_iter_u32, (U32, Max), [u32] => Option<u32>);
+kernel!(find_u32_avx512, avx512::u32_impl::find_avx512, find_iter_u32, (U32, Find), [u32], needle: u32 => Option<usize>);
+kernel!(contains_u32_avx512, avx512::u32_impl::contains_avx512, contains_iter_u32, (U32, Contains), [u32], needle: u32 => bool);
+kernel!(count_u32_avx512, avx512::u32_impl::count_avx512, count_iter_u32, (U32, Count), [u32], element: u32 => usize);
+
+kernel!(min_f32_avx512, avx512::f32_impl::min_avx512, min_iter_f32, (F32, Min), [f32], mode: NanMode => Option<f32>);
+kernel!(max_f32_avx512, avx512::f32_impl::max_avx512, max_iter_f32, (F32, Max), [f32], mode: NanMode => Option<f32>);
+
+/// Kernels requiring AVX-512 F and BW, as well as AVX2 for the element types without AVX-512 kernels.
+pub(crate) static AVX512: Kernels = Kernels {
+    backend: Backend::Avx512,
+    u8: U8Kernels {
+        min: min_u8_avx512,
+        max: max_u8_avx512,
+        min_max: min_max_u8_avx2,
+        find: find_u8_avx512,
+        rfind: rfind_u8_avx2,
+        mismatch: mismatch_u8_avx2,
+        all_eq: all_eq_u8_avx2,
+        count_in_range: count_in_range_u8_avx2,
+        find_in_range: find_in_range_u8_avx2,
+        find_all: find_all_u8_avx2,
+        find_any2: find_any2_u8_avx2,
+        find_any3: find_any3_u8_avx2,
+        find_any_of: find_any_of_u8_avx2,
+        find_subslice: find_subslice_u8_avx2,
+        contains: contains_u8_avx512,
+        count: count_u8_avx512,
+        argmin: argmin_u8_avx2,
+        argmax: argmax_u8_avx2,
+        sum: sum_u8_avx2,
+    },
+    u16: U16Kernels {
+        min: min_u16_avx2,
+        max: max_u16_avx2,
+        min_max: min_max_u16_avx2,
+        find: find_u16_avx2,
+        contains: contains_u16_avx2,
+        count: count_u16_avx2,
+    },
+    u32: U32Kernels {
+        min: min_u32_avx512,
+        max: max_u32_avx512,
+        min_max: min_max_u32_avx2,
+        find: find_u32_avx512,
+        rfind: rfind_u32_avx2,
+        mismatch: mismatch_u32_avx2,
+        all_eq: all_eq_u32_avx2,
+        count_in_range: count_in_range_u32_avx2,
+        find_in_range: find_in_range_u32_avx2,
+        find_all: find_all_u32_avx2,
+        contains: contains_u32_avx512,
+        count: count_u32_avx512,
+        argmin: argmin_u32_avx2,
+        argmax: argmax_u32_avx2,
+        sum: sum_u32_avx2,
+    },
+    u64: U64Kernels {
+        min: min_u64_avx2,
+        max: max_u64_avx2,
+        min_max: min_max_u64_avx2,
+        find: find_u64_avx2,
+        contains: contains_u64_avx2,
+        count: count_u64_avx2,
+    },
+    i8: I8Kernels {
+        min: min_i8_avx2,
+        max: max_i8_avx2,
+        min_max: min_max_i8_avx2,
+        find: find_i8_avx2,
+        contains: contains_i8_avx2,
+        count: count_i8_avx2,
+    },
+    i16: I16Kernels {
+        min: min_i16_avx2,
+        max: max_i16_avx2,
+        min_max: min_max_i16_avx2,
+        find: find_i16_avx2,
+        contains: contains_i16_avx2,
+        count: count_i16_avx2,
+    },
+    i32: I32Kernels {
+        min: min_i32_avx2,
+        max: max_i32_avx2,
+        min_max: min_max_i32_avx2,
+        find: find_i32_avx2,
+        contains: contains_i32_avx2,
+        count: count_i32_avx2,
+    },
+    i64: I64Kernels {
+        min: min_i64_avx2,
+        max: max_i64_avx2,
+        min_max: min_max_i64_avx2,
+        find: find_i64_avx2,
+        contains: contains_i64_avx2,
+        count: count_i64_avx2,
+    },
+    f32: F32Kernels {
+        min: min_f32_avx512,
+        max: max_f32_avx512,
+        min_max: min_max_f32_avx2,
+        argmin: argmin_f32_avx2,
+        argmax: argmax_f32_avx2,
+        sum: sum_f32_avx2,
+        mismatch: mismatch_f32_avx2,
+        count_in_range: count_in_range_f32_avx2,
+        find_in_range: find_in_range_f32_avx2,
+    },
+    f64: F64Kernels {
+        min: min_f64_avx2,
+        max: max_f64_avx2,
+        min_max: min_max_f64_avx2,
+        find: find_f64_avx2,
+        contains: contains_f64_avx2,
+        count: count_f64_avx2,
+        sum: sum_f64_avx2,
+    },
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dispatch::scalar;
+
+    /// Asserts for every table that each field using a vectorized kernel instead of the original implementation
+    /// belongs to an operation listed by [`Element::operations`], so it gets reported and calibrated.
+    ///
+    /// The kernels are destructured without `..`, so a new field doesn't compile until it is listed here.
+    macro_rules! assert_listed {
+        ($field:ident: $kernels:ident, $element:ident, { $($kernel:ident: $operation:ident),* $(,)? }) => {
+            for table in [&SSE, &AVX2, &AVX512] {
+                let $kernels { $($kernel),* } = &table.$field;
+                let original = &scalar::KERNELS.$field;
+
+                $(
+                    if *$kernel as usize != original.$kernel as usize {
+                        assert!(
+                            Element::$element.operations().contains(&Operation::$operation),
+                            "{} uses a kernel for {:?} on {:?}, but it isn't listed",
+                            table.backend,
+                            Operation::$operation,
+                            Element::$element,
+                        );
+                    }
+                )*
+            }
+        };
+    }
+
+    #[test]
+    fn test_kernels_listed() {
+        assert_listed!(u8: U8Kernels, U8, {
+            min: Min,
+            max: Max,
+            min_max: MinMax,
+            find: Find,
+            rfind: RFind,
+            mismatch: Mismatch,
+            find_all: FindAll,
+            find_any2: FindAny2,
+            find_any3: FindAny3,
+            find_any_of: FindAnyOf,
+            find_subslice: FindSubslice,
+            contains: Contains,
+            all_eq: AllEq,
+            count: Count,
+            count_in_range: CountInRange,
+            find_in_range: FindInRange,
+            argmin: ArgMin,
+            argmax: ArgMax,
+            sum: Sum,
+        });
+        assert_listed!(u32: U32Kernels, U32, {
+            min: Min,
+            max: Max,
+            min_max: MinMax,
+            find: Find,
+            rfind: RFind,
+            mismatch: Mismatch,
+            find_all: FindAll,
+            contains: Contains,
+            all_eq: AllEq,
+            count: Count,
+            count_in_range: CountInRange,
+            find_in_range: FindInRange,
+            argmin: ArgMin,
+            argmax: ArgMax,
+            sum: Sum,
+        });
+        assert_listed!(f32: F32Kernels, F32, {
+            min: Min,
+            max: Max,
+            min_max: MinMax,
+            argmin: ArgMin,
+            argmax: ArgMax,
+            sum: Sum,
+            mismatch: Mismatch,
+            count_in_range: CountInRange,
+            find_in_range: FindInRange,
+        });
+        assert_listed!(f64: F64Kernels, F64, {
+            min: Min,
+            max: Max,
+            min_max: MinMax,
+            find: Find,
+            contains: Contains,
+            count: Count,
+            sum: Sum,
+        });
+        assert_listed!(u16: U16Kernels, U16, {
+            min: Min, max: Max, min_max: MinMax, find: Find, contains: Contains, count: Count,
+        });
+        assert_listed!(u64: U64Kernels, U64, {
+            min: Min, max: Max, min_max: MinMax, find: Find, contains: Contains, count: Count,
+        });
+        assert_listed!(i8: I8Kernels, I8, {
+            min: Min, max: Max, min_max: MinMax, find: Find, contains: Contains, count: Count,
+        });
+        assert_listed!(i16: I16Kernels, I16, {
+            min: Min, max: Max, min_max: MinMax, find: Find, contains: Contains, count: Count,
+        });
+        assert_listed!(i32: I32Kernels, I32, {
+            min: Min, max: Max, min_max: MinMax, find: Find, contains: Contains, count: Count,
+        });
+        assert_listed!(i64: I64Kernels, I64, {
+            min: Min, max: Max, min_max: MinMax, find: Find, contains: Contains, count: Count,
+        });
+    }
+}
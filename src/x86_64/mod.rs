@@ -1,6 +1,3 @@
 pub mod array;
+pub(crate) mod dispatch;
 pub mod simd_extensions;
-
-pub(crate) const AVX2_U32_MIN_SIZE: usize = 32;
-pub(crate) const AVX2_F32_MIN_SIZE: usize = 32;
-pub(crate) const AVX2_U8_MIN_SIZE: usize = 32;
@@ -1,14 +1,22 @@
 use std::{
     arch::x86_64::{
-        __m256, __m256i, _mm_add_epi32, _mm_extract_epi32, _mm_extract_ps, _mm_hadd_epi32,
-        _mm_max_epu8, _mm_max_epu32, _mm_max_ps, _mm_min_epu8, _mm_min_epu32, _mm_min_ps,
-        _mm_shuffle_epi32, _mm_shuffle_ps, _mm256_castps256_ps128, _mm256_castsi256_si128,
-        _mm256_extractf128_ps, _mm256_extracti128_si256,
+        __m128, __m128i, __m256, __m256d, __m256i, _mm_add_epi32, _mm_add_epi64, _mm_add_pd,
+        _mm_add_ps, _mm_add_sd, _mm_add_ss, _mm_castps_si128, _mm_cvtsd_f64, _mm_cvtsi128_si64,
+        _mm_cvtss_f32, _mm_extract_epi16, _mm_extract_epi32, _mm_hadd_epi32, _mm_max_epi8,
+        _mm_max_epi16, _mm_max_epi32, _mm_max_epu8, _mm_max_epu16, _mm_max_epu32, _mm_min_epi8,
+        _mm_min_epi16, _mm_min_epi32, _mm_min_epu8, _mm_min_epu16, _mm_min_epu32, _mm_minpos_epu16,
+        _mm_movehdup_ps, _mm_movehl_ps, _mm_set1_epi16, _mm_shuffle_epi32, _mm_srai_epi32,
+        _mm_srli_epi32, _mm_unpackhi_epi64, _mm_unpackhi_pd, _mm_xor_si128, _mm256_castpd_si256,
+        _mm256_castpd256_pd128, _mm256_castps_si256, _mm256_castps256_ps128,
+        _mm256_castsi256_si128, _mm256_cmpgt_epi64, _mm256_extractf128_pd, _mm256_extractf128_ps,
+        _mm256_extracti128_si256, _mm256_setzero_si256, _mm256_srai_epi32, _mm256_srli_epi32,
+        _mm256_srli_epi64, _mm256_xor_si256,
     },
     mem::transmute,
 };
 
 /// Calculates the horizontal sum of 8x 32bit integers.
+#[inline]
 #[target_feature(enable = "avx2")]
 pub fn negative_horizontal_sum_u32_avx(input: __m256i) -> u32 {
     let sum_128 = _mm_add_epi32(
@@ -19,9 +27,9 @@ pub fn negative_horizontal_sum_u32_avx(input: __m256i) -> u32 {
     (-(_mm_extract_epi32::<0>(hsum) as i32) + (-(_mm_extract_epi32::<1>(hsum) as i32))) as u32
 }
 
-/// Calculates the horizontal sum of 8x 32bit integers.
+/// Calculates the horizontal sum of 8x 32bit integers. The sum has to fit into a `u32`.
+#[inline]
 #[target_feature(enable = "avx2")]
-#[allow(dead_code)]
 pub fn horizontal_sum_u32_avx(input: __m256i) -> u32 {
     let sum128 = _mm_add_epi32(
         _mm256_castsi256_si128(input),
@@ -31,7 +39,19 @@ pub fn horizontal_sum_u32_avx(input: __m256i) -> u32 {
     _mm_extract_epi32::<0>(hsum) as u32 + _mm_extract_epi32::<1>(hsum) as u32
 }
 
+/// Calculates the horizontal sum of 4x 64bit integers.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_sum_u64_avx(input: __m256i) -> u64 {
+    let sum128 = _mm_add_epi64(
+        _mm256_castsi256_si128(input),
+        _mm256_extracti128_si256::<1>(input),
+    );
+    horizontal_sum_u64_sse(sum128)
+}
+
 /// Calculates the horizontal maximum of 32x u8.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn horizontal_max_u8_avx(a: __m256i) -> u8 {
@@ -45,6 +65,7 @@ pub fn horizontal_max_u8_avx(a: __m256i) -> u8 {
 }
 
 /// Calculates the horizontal minimum of 32x u8.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn horizontal_min_u8_avx(a: __m256i) -> u8 {
@@ -58,6 +79,7 @@ pub fn horizontal_min_u8_avx(a: __m256i) -> u8 {
 }
 
 /// Calculates the horizontal minimum of 8x u32.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn horizontal_min_u32_avx(a: __m256i) -> u32 {
@@ -68,6 +90,7 @@ pub fn horizontal_min_u32_avx(a: __m256i) -> u32 {
 }
 
 /// Calculates the horizontal maximum of 8x u32.
+#[inline]
 #[target_feature(enable = "avx")]
 #[target_feature(enable = "avx2")]
 pub fn horizontal_max_u32_avx(a: __m256i) -> u32 {
@@ -77,36 +100,260 @@ pub fn horizontal_max_u32_avx(a: __m256i) -> u32 {
     (_mm_extract_epi32::<0>(min64) as u32).max(_mm_extract_epi32::<1>(min64) as u32)
 }
 
-/// Calculates the horizontal minimum of 8x f32.
-#[target_feature(enable = "avx")]
+/// Calculates the horizontal sum of 4x 32bit integers.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn horizontal_sum_u32_sse(input: __m128i) -> u32 {
+    // Safety: we can safely transmute a __m128i to [u32; 4]
+    let array: [u32; 4] = unsafe { transmute(input) };
+    array.iter().fold(0u32, |acc, i| acc.wrapping_add(*i))
+}
+
+/// Calculates the horizontal sum of 2x 64bit integers.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn horizontal_sum_u64_sse(input: __m128i) -> u64 {
+    let sum = _mm_add_epi64(input, _mm_unpackhi_epi64(input, input));
+    _mm_cvtsi128_si64(sum) as u64
+}
+
+/// Calculates the horizontal minimum of 16x u8.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn horizontal_min_u8_sse(a: __m128i) -> u8 {
+    // Safety: we can safely transmute a __m128i to [u8; 16]
+    let array: [u8; 16] = unsafe { transmute(a) };
+
+    // Safety: `array` is always of length 16.
+    unsafe { *array.iter().min().unwrap_unchecked() }
+}
+
+/// Calculates the horizontal maximum of 16x u8.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn horizontal_max_u8_sse(a: __m128i) -> u8 {
+    // Safety: we can safely transmute a __m128i to [u8; 16]
+    let array: [u8; 16] = unsafe { transmute(a) };
+
+    // Safety: `array` is always of length 16.
+    unsafe { *array.iter().max().unwrap_unchecked() }
+}
+
+/// Calculates the horizontal minimum of 4x u32.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub fn horizontal_min_u32_sse(a: __m128i) -> u32 {
+    let shuffled = _mm_shuffle_epi32(a, 0b01_00_11_10);
+    let min64 = _mm_min_epu32(a, shuffled);
+    (_mm_extract_epi32::<0>(min64) as u32).min(_mm_extract_epi32::<1>(min64) as u32)
+}
+
+/// Calculates the horizontal maximum of 4x u32.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub fn horizontal_max_u32_sse(a: __m128i) -> u32 {
+    let shuffled = _mm_shuffle_epi32(a, 0b01_00_11_10);
+    let max64 = _mm_max_epu32(a, shuffled);
+    (_mm_extract_epi32::<0>(max64) as u32).max(_mm_extract_epi32::<1>(max64) as u32)
+}
+
+/// Calculates the horizontal minimum of 16x u16.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_min_u16_avx(a: __m256i) -> u16 {
+    let min128 = _mm_min_epu16(_mm256_castsi256_si128(a), _mm256_extracti128_si256::<1>(a));
+    _mm_extract_epi16::<0>(_mm_minpos_epu16(min128)) as u16
+}
+
+/// Calculates the horizontal maximum of 16x u16.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_max_u16_avx(a: __m256i) -> u16 {
+    let max128 = _mm_max_epu16(_mm256_castsi256_si128(a), _mm256_extracti128_si256::<1>(a));
+
+    // The maximum is the inverted minimum of the inverted values.
+    let inverted = _mm_xor_si128(max128, _mm_set1_epi16(-1));
+    !(_mm_extract_epi16::<0>(_mm_minpos_epu16(inverted)) as u16)
+}
+
+/// Calculates the horizontal minimum of 4x u64.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_min_u64_avx(a: __m256i) -> u64 {
+    // Safety: we can safely transmute a __m256i to [u64; 4]
+    let array: [u64; 4] = unsafe { transmute(a) };
+
+    // Safety: `array` is always of length 4.
+    unsafe { *array.iter().min().unwrap_unchecked() }
+}
+
+/// Calculates the horizontal maximum of 4x u64.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_max_u64_avx(a: __m256i) -> u64 {
+    // Safety: we can safely transmute a __m256i to [u64; 4]
+    let array: [u64; 4] = unsafe { transmute(a) };
+
+    // Safety: `array` is always of length 4.
+    unsafe { *array.iter().max().unwrap_unchecked() }
+}
+
+/// Calculates the horizontal minimum of 32x i8.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_min_i8_avx(a: __m256i) -> i8 {
+    let min128 = _mm_min_epi8(_mm256_castsi256_si128(a), _mm256_extracti128_si256::<1>(a));
+
+    // Safety: we can safely transmute a __m128i to [i8; 16]
+    let array: [i8; 16] = unsafe { transmute(min128) };
+
+    // Safety: `array` is always of length 16.
+    unsafe { *array.iter().min().unwrap_unchecked() }
+}
+
+/// Calculates the horizontal maximum of 32x i8.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_max_i8_avx(a: __m256i) -> i8 {
+    let max128 = _mm_max_epi8(_mm256_castsi256_si128(a), _mm256_extracti128_si256::<1>(a));
+
+    // Safety: we can safely transmute a __m128i to [i8; 16]
+    let array: [i8; 16] = unsafe { transmute(max128) };
+
+    // Safety: `array` is always of length 16.
+    unsafe { *array.iter().max().unwrap_unchecked() }
+}
+
+/// Calculates the horizontal minimum of 16x i16.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_min_i16_avx(a: __m256i) -> i16 {
+    let min128 = _mm_min_epi16(_mm256_castsi256_si128(a), _mm256_extracti128_si256::<1>(a));
+
+    // Safety: we can safely transmute a __m128i to [i16; 8]
+    let array: [i16; 8] = unsafe { transmute(min128) };
+
+    // Safety: `array` is always of length 8.
+    unsafe { *array.iter().min().unwrap_unchecked() }
+}
+
+/// Calculates the horizontal maximum of 16x i16.
+#[inline]
 #[target_feature(enable = "avx2")]
-pub fn horizontal_min_f32_avx(a: __m256) -> f32 {
-    let min128 = _mm_min_ps(_mm256_castps256_ps128(a), _mm256_extractf128_ps::<1>(a));
-    let min128_shuffled = _mm_shuffle_ps(min128, min128, 0b01_00_11_10);
-    let min64 = _mm_min_ps(min128, min128_shuffled);
+pub fn horizontal_max_i16_avx(a: __m256i) -> i16 {
+    let max128 = _mm_max_epi16(_mm256_castsi256_si128(a), _mm256_extracti128_si256::<1>(a));
 
-    let hi = f32::from_bits(_mm_extract_ps::<0>(min64) as u32);
-    let lo = f32::from_bits(_mm_extract_ps::<1>(min64) as u32);
+    // Safety: we can safely transmute a __m128i to [i16; 8]
+    let array: [i16; 8] = unsafe { transmute(max128) };
 
-    if hi < lo { hi } else { lo }
+    // Safety: `array` is always of length 8.
+    unsafe { *array.iter().max().unwrap_unchecked() }
 }
 
-/// Calculates the horizontal minimum of 8x f32.
+/// Calculates the horizontal minimum of 8x i32.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_min_i32_avx(a: __m256i) -> i32 {
+    let min128 = _mm_min_epi32(_mm256_castsi256_si128(a), _mm256_extracti128_si256::<1>(a));
+    let min64 = _mm_min_epi32(min128, _mm_shuffle_epi32(min128, 0b01_00_11_10));
+    _mm_extract_epi32::<0>(min64).min(_mm_extract_epi32::<1>(min64))
+}
+
+/// Calculates the horizontal maximum of 8x i32.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_max_i32_avx(a: __m256i) -> i32 {
+    let max128 = _mm_max_epi32(_mm256_castsi256_si128(a), _mm256_extracti128_si256::<1>(a));
+    let max64 = _mm_max_epi32(max128, _mm_shuffle_epi32(max128, 0b01_00_11_10));
+    _mm_extract_epi32::<0>(max64).max(_mm_extract_epi32::<1>(max64))
+}
+
+/// Calculates the horizontal minimum of 4x i64.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_min_i64_avx(a: __m256i) -> i64 {
+    // Safety: we can safely transmute a __m256i to [i64; 4]
+    let array: [i64; 4] = unsafe { transmute(a) };
+
+    // Safety: `array` is always of length 4.
+    unsafe { *array.iter().min().unwrap_unchecked() }
+}
+
+/// Calculates the horizontal maximum of 4x i64.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn horizontal_max_i64_avx(a: __m256i) -> i64 {
+    // Safety: we can safely transmute a __m256i to [i64; 4]
+    let array: [i64; 4] = unsafe { transmute(a) };
+
+    // Safety: `array` is always of length 4.
+    unsafe { *array.iter().max().unwrap_unchecked() }
+}
+
+/// Calculates the horizontal sum of 4x f64.
+#[inline]
 #[target_feature(enable = "avx")]
+pub fn horizontal_sum_f64_avx(a: __m256d) -> f64 {
+    let sum128 = _mm_add_pd(_mm256_castpd256_pd128(a), _mm256_extractf128_pd::<1>(a));
+    _mm_cvtsd_f64(_mm_add_sd(sum128, _mm_unpackhi_pd(sum128, sum128)))
+}
+
+/// Calculates the horizontal sum of 8x f32.
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn horizontal_sum_f32_avx(a: __m256) -> f32 {
+    let sum128 = _mm_add_ps(_mm256_castps256_ps128(a), _mm256_extractf128_ps::<1>(a));
+    let sum64 = _mm_add_ps(sum128, _mm_movehl_ps(sum128, sum128));
+    _mm_cvtss_f32(_mm_add_ss(sum64, _mm_movehdup_ps(sum64)))
+}
+
+/// Maps the bits of 8x f32 to integers that are ordered like [`f32::total_cmp`]. The mapping is its own inverse.
+#[inline]
 #[target_feature(enable = "avx2")]
-pub fn horizontal_max_f32_avx(a: __m256) -> f32 {
-    let min128 = _mm_max_ps(_mm256_castps256_ps128(a), _mm256_extractf128_ps::<1>(a));
-    let min128_shuffled = _mm_shuffle_ps(min128, min128, 0b01_00_11_10);
-    let min64 = _mm_max_ps(min128, min128_shuffled);
+pub fn total_order_key_f32_avx(a: __m256) -> __m256i {
+    let bits = _mm256_castps_si256(a);
+    _mm256_xor_si256(bits, _mm256_srli_epi32::<1>(_mm256_srai_epi32::<31>(bits)))
+}
 
-    let hi = f32::from_bits(_mm_extract_ps::<0>(min64) as u32);
-    let lo = f32::from_bits(_mm_extract_ps::<1>(min64) as u32);
-    if hi > lo { hi } else { lo }
+/// Maps the bits of 4x f64 to integers that are ordered like [`f64::total_cmp`]. The mapping is its own inverse.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub fn total_order_key_f64_avx(a: __m256d) -> __m256i {
+    let bits = _mm256_castpd_si256(a);
+    // AVX2 can't shift 64 bit lanes arithmetically, so the sign gets spread by a comparison.
+    let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), bits);
+    _mm256_xor_si256(bits, _mm256_srli_epi64::<1>(sign))
+}
+
+/// Maps the bits of 4x f32 to integers that are ordered like [`f32::total_cmp`]. The mapping is its own inverse.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn total_order_key_f32_sse(a: __m128) -> __m128i {
+    let bits = _mm_castps_si128(a);
+    _mm_xor_si128(bits, _mm_srli_epi32::<1>(_mm_srai_epi32::<31>(bits)))
+}
+
+/// Calculates the horizontal minimum of 4x i32.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub fn horizontal_min_i32_sse(a: __m128i) -> i32 {
+    let min64 = _mm_min_epi32(a, _mm_shuffle_epi32(a, 0b01_00_11_10));
+    _mm_extract_epi32::<0>(min64).min(_mm_extract_epi32::<1>(min64))
+}
+
+/// Calculates the horizontal maximum of 4x i32.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub fn horizontal_max_i32_sse(a: __m128i) -> i32 {
+    let max64 = _mm_max_epi32(a, _mm_shuffle_epi32(a, 0b01_00_11_10));
+    _mm_extract_epi32::<0>(max64).max(_mm_extract_epi32::<1>(max64))
 }
 
 #[cfg(test)]
 mod test {
-    use std::arch::x86_64::{_mm256_set1_epi32, _mm256_setr_epi32, _mm256_setzero_si256};
+    use std::arch::x86_64::{
+        _mm256_set1_epi32, _mm256_setr_epi32, _mm256_setr_pd, _mm256_setr_ps, _mm256_setzero_si256,
+    };
 
     use rand::rngs::StdRng;
     use rand::{RngCore, SeedableRng};
@@ -133,6 +380,9 @@ mod test {
             // Test all 0
             let input = _mm256_setzero_si256();
             assert_eq!(horizontal_sum_u32_avx(input), 0);
+
+            let input = _mm256_setr_ps(1.5, -2.0, 3.25, 4.0, 0.5, 6.0, -7.0, 8.0);
+            assert_eq!(horizontal_sum_f32_avx(input), 14.25);
         }
     }
 
@@ -158,6 +408,24 @@ mod test {
                 let max = horizontal_max_u32_avx(input_reg);
                 let real_max = input.iter().map(|i| *i as u32).max().unwrap();
                 assert_eq!(max, real_max);
+
+                // Reinterpret the same lanes as 16x u16.
+                let input_u16: [u16; 16] = transmute(input);
+
+                let min = horizontal_min_u16_avx(input_reg);
+                assert_eq!(min, *input_u16.iter().min().unwrap());
+
+                let max = horizontal_max_u16_avx(input_reg);
+                assert_eq!(max, *input_u16.iter().max().unwrap());
+
+                let input_f64: [f64; 4] =
+                    [0, 2, 4, 6].map(|i| input[i] as f64 / input[i + 1] as f64);
+                let input_reg =
+                    _mm256_setr_pd(input_f64[0], input_f64[1], input_f64[2], input_f64[3]);
+
+                let sum = horizontal_sum_f64_avx(input_reg);
+                let expected: f64 = input_f64.iter().sum();
+                assert!((sum - expected).abs() <= expected.abs() * 1e-12);
             }
         }
     }
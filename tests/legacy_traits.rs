@@ -0,0 +1,119 @@
+//! The per-type traits that existed before `SliceSimdExt`, used the way code written against them does.
+
+mod by_path {
+    use quicksim::NanMode;
+    use quicksim::traits::array_f32::ArrayF32SimdExt;
+    use quicksim::traits::array_f64::ArrayF64SimdExt;
+    use quicksim::traits::array_i8::ArrayI8SimdExt;
+    use quicksim::traits::array_i16::ArrayI16SimdExt;
+    use quicksim::traits::array_i32::ArrayI32SimdExt;
+    use quicksim::traits::array_i64::ArrayI64SimdExt;
+    use quicksim::traits::array_u8::ArrayU8SimdExt;
+    use quicksim::traits::array_u16::ArrayU16SimdExt;
+    use quicksim::traits::array_u32::ArrayU32SimdExt;
+    use quicksim::traits::array_u64::ArrayU64SimdExt;
+
+    macro_rules! test_integer_trait {
+        ($name:ident, $trait:ident, $ty:ty) => {
+            #[test]
+            fn $name() {
+                let vec: Vec<$ty> = (0..100).map(|i| (i % 50) as $ty).collect();
+                let slice: &[$ty] = &vec;
+
+                assert_eq!(vec.min_simd(), Some(0));
+                assert_eq!(vec.max_simd(), Some(49));
+                assert_eq!(vec.find_simd(7), Some(7));
+                assert!(vec.contains_simd(49));
+                assert_eq!(vec.count_simd(3), 2);
+
+                assert_eq!(slice.max_simd(), Some(49));
+                assert_eq!([5 as $ty; 40].find_simd(5), Some(0));
+
+                assert_eq!($trait::min_simd(&vec), Some(0));
+                assert_eq!(<Vec<$ty> as $trait>::count_simd(&vec, 3), 2);
+
+                fn largest<A: $trait>(array: &A) -> Option<$ty> {
+                    array.max_simd()
+                }
+
+                assert_eq!(largest(&vec), Some(49));
+                assert_eq!(largest(&slice), Some(49));
+                assert_eq!(largest(&[1 as $ty, 2, 3]), Some(3));
+            }
+        };
+    }
+
+    test_integer_trait!(test_array_u8, ArrayU8SimdExt, u8);
+    test_integer_trait!(test_array_u16, ArrayU16SimdExt, u16);
+    test_integer_trait!(test_array_u32, ArrayU32SimdExt, u32);
+    test_integer_trait!(test_array_u64, ArrayU64SimdExt, u64);
+    test_integer_trait!(test_array_i8, ArrayI8SimdExt, i8);
+    test_integer_trait!(test_array_i16, ArrayI16SimdExt, i16);
+    test_integer_trait!(test_array_i32, ArrayI32SimdExt, i32);
+    test_integer_trait!(test_array_i64, ArrayI64SimdExt, i64);
+
+    #[test]
+    fn test_array_f32() {
+        let vec: Vec<f32> = (0..100).map(|i| i as f32).chain([f32::NAN]).collect();
+
+        assert_eq!(vec.min_simd(), Some(0.0));
+        assert_eq!(vec.max_simd(), Some(99.0));
+        assert!(vec.max_simd_with(NanMode::Propagate).unwrap().is_nan());
+        assert!(
+            ArrayF32SimdExt::min_simd_with(&vec, NanMode::Propagate)
+                .unwrap()
+                .is_nan()
+        );
+
+        fn smallest<A: ArrayF32SimdExt>(array: &A) -> Option<f32> {
+            array.min_simd()
+        }
+
+        assert_eq!(smallest(&vec), Some(0.0));
+    }
+
+    #[test]
+    fn test_array_f64() {
+        let vec: Vec<f64> = (0..100).map(|i| (i % 50) as f64).collect();
+
+        assert_eq!(vec.min_simd(), Some(0.0));
+        assert_eq!(vec.max_simd(), Some(49.0));
+        assert_eq!(vec.find_simd(7.0), Some(7));
+        assert!(vec.contains_simd(49.0));
+        assert_eq!(vec.count_simd(3.0), 2);
+        assert_eq!(vec.sum_simd(), 2450.0);
+        assert_eq!(ArrayF64SimdExt::sum_simd(&vec), 2450.0);
+
+        fn total<A: ArrayF64SimdExt>(array: &A) -> f64 {
+            array.sum_simd()
+        }
+
+        assert_eq!(total(&[1.0, 2.0, 3.0]), 6.0);
+    }
+}
+
+mod prelude {
+    use quicksim::prelude::*;
+
+    #[test]
+    fn test_prelude_methods_unambiguous() {
+        let vec: Vec<u32> = (0..100).collect();
+        let slice: &[u32] = &vec;
+        let borrowed = &vec;
+        let array = [4u8; 40];
+
+        // The per-type traits and `SliceSimdExt` are both in scope.
+        assert_eq!(vec.min_simd(), Some(0));
+        assert_eq!(slice.max_simd(), Some(99));
+        assert_eq!(array.count_simd(4), 40);
+        assert_eq!(borrowed.find_simd(42), Some(42));
+
+        // Operations only `SliceSimdExt` provides.
+        assert_eq!(vec.min_max_simd(), Some((0, 99)));
+        assert_eq!(slice.rfind_simd(42), Some(42));
+        assert!(array.all_eq_simd(4));
+
+        assert_eq!(ArrayU32SimdExt::min_simd(&vec), Some(0));
+        assert_eq!(SliceSimdExt::min_simd(slice), Some(0));
+    }
+}